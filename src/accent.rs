@@ -0,0 +1,744 @@
+use crate::preverb::bare;
+
+// Each vowel, with or without breathing and iota subscript, and its acute
+const ACUTE: [(char, char); 30] = [
+    ('α', 'ά'),
+    ('ἀ', 'ἄ'),
+    ('ἁ', 'ἅ'),
+    ('ε', 'έ'),
+    ('ἐ', 'ἔ'),
+    ('ἑ', 'ἕ'),
+    ('η', 'ή'),
+    ('ἠ', 'ἤ'),
+    ('ἡ', 'ἥ'),
+    ('ι', 'ί'),
+    ('ἰ', 'ἴ'),
+    ('ἱ', 'ἵ'),
+    ('ο', 'ό'),
+    ('ὀ', 'ὄ'),
+    ('ὁ', 'ὅ'),
+    ('υ', 'ύ'),
+    ('ὐ', 'ὔ'),
+    ('ὑ', 'ὕ'),
+    ('ω', 'ώ'),
+    ('ὠ', 'ὤ'),
+    ('ὡ', 'ὥ'),
+    ('ᾳ', 'ᾴ'),
+    ('ᾀ', 'ᾄ'),
+    ('ᾁ', 'ᾅ'),
+    ('ῃ', 'ῄ'),
+    ('ᾐ', 'ᾔ'),
+    ('ᾑ', 'ᾕ'),
+    ('ῳ', 'ῴ'),
+    ('ᾠ', 'ᾤ'),
+    ('ᾡ', 'ᾥ'),
+];
+
+// and its circumflex, which ε and ο, being short, never take
+const CIRCUMFLEX: [(char, char); 24] = [
+    ('α', 'ᾶ'),
+    ('ἀ', 'ἆ'),
+    ('ἁ', 'ἇ'),
+    ('η', 'ῆ'),
+    ('ἠ', 'ἦ'),
+    ('ἡ', 'ἧ'),
+    ('ι', 'ῖ'),
+    ('ἰ', 'ἶ'),
+    ('ἱ', 'ἷ'),
+    ('υ', 'ῦ'),
+    ('ὐ', 'ὖ'),
+    ('ὑ', 'ὗ'),
+    ('ω', 'ῶ'),
+    ('ὠ', 'ὦ'),
+    ('ὡ', 'ὧ'),
+    ('ᾳ', 'ᾷ'),
+    ('ᾀ', 'ᾆ'),
+    ('ᾁ', 'ᾇ'),
+    ('ῃ', 'ῇ'),
+    ('ᾐ', 'ᾖ'),
+    ('ᾑ', 'ᾗ'),
+    ('ῳ', 'ῷ'),
+    ('ᾠ', 'ᾦ'),
+    ('ᾡ', 'ᾧ'),
+];
+
+// and its grave, which is only ever met with in what is typed in
+const GRAVE: [(char, char); 30] = [
+    ('α', 'ὰ'),
+    ('ἀ', 'ἂ'),
+    ('ἁ', 'ἃ'),
+    ('ε', 'ὲ'),
+    ('ἐ', 'ἒ'),
+    ('ἑ', 'ἓ'),
+    ('η', 'ὴ'),
+    ('ἠ', 'ἢ'),
+    ('ἡ', 'ἣ'),
+    ('ι', 'ὶ'),
+    ('ἰ', 'ἲ'),
+    ('ἱ', 'ἳ'),
+    ('ο', 'ὸ'),
+    ('ὀ', 'ὂ'),
+    ('ὁ', 'ὃ'),
+    ('υ', 'ὺ'),
+    ('ὐ', 'ὒ'),
+    ('ὑ', 'ὓ'),
+    ('ω', 'ὼ'),
+    ('ὠ', 'ὢ'),
+    ('ὡ', 'ὣ'),
+    ('ᾳ', 'ᾲ'),
+    ('ᾀ', 'ᾂ'),
+    ('ᾁ', 'ᾃ'),
+    ('ῃ', 'ῂ'),
+    ('ᾐ', 'ᾒ'),
+    ('ᾑ', 'ᾓ'),
+    ('ῳ', 'ῲ'),
+    ('ᾠ', 'ᾢ'),
+    ('ᾡ', 'ᾣ'),
+];
+
+// Other accents typed in: the oxia forms of the acute, which look just like
+// the tonos ones above, and an accented diaeresis
+const TYPED: [(char, char); 11] = [
+    ('α', 'ά'),
+    ('ε', 'έ'),
+    ('η', 'ή'),
+    ('ι', 'ί'),
+    ('ο', 'ό'),
+    ('υ', 'ύ'),
+    ('ω', 'ώ'),
+    ('ϊ', 'ΐ'),
+    ('ϊ', 'ΐ'),
+    ('ϋ', 'ΰ'),
+    ('ϋ', 'ΰ'),
+];
+
+// ι and υ marked long, which only the accent rules see. The stems give them
+// where the length is known, ἐλῡσα, and they are written plain again once
+// the form is accented: λῦσαι, λῦσον.
+const LONG: [(char, char); 2] = [('ι', 'ῑ'), ('υ', 'ῡ')];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Accent {
+    Acute,
+    Circumflex,
+}
+
+// The vowel or diphthong of a syllable, as a char index and length
+#[derive(Debug, Clone)]
+struct Syllable {
+    at: usize,
+    len: usize,
+    long: bool,
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(
+        bare(short(c)),
+        'α' | 'ε' | 'η' | 'ι' | 'ο' | 'υ' | 'ω' | 'ᾳ' | 'ῃ' | 'ῳ'
+    )
+}
+
+pub fn long(c: char) -> char {
+    LONG.iter()
+        .find(|(short, _)| c == *short)
+        .map_or(c, |(_, long)| *long)
+}
+
+fn short(c: char) -> char {
+    LONG.iter()
+        .find(|(_, long)| c == *long)
+        .map_or(c, |(short, _)| *short)
+}
+
+// A breathing on the first letter means the two are said apart
+fn is_diphthong(first: char, second: char) -> bool {
+    bare(first) == first
+        && ((matches!(first, 'α' | 'ε' | 'η' | 'ο') && matches!(bare(second), 'ι' | 'υ'))
+            || (first == 'υ' && bare(second) == 'ι'))
+}
+
+// α, ι and υ may be long or short, and nothing in an unaccented stem says
+// which, so they are taken as short unless marked long. Accents are looked
+// through.
+fn syllables(chars: &[char]) -> Vec<Syllable> {
+    let chars: Vec<char> = chars.iter().map(|c| plain(*c)).collect();
+    let mut syllables = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if !is_vowel(chars[i]) {
+            i += 1;
+        } else if i + 1 < chars.len() && is_diphthong(chars[i], chars[i + 1]) {
+            syllables.push(Syllable {
+                at: i,
+                len: 2,
+                long: true,
+            });
+            i += 2;
+        } else {
+            let long = matches!(bare(chars[i]), 'η' | 'ω' | 'ᾳ' | 'ῃ' | 'ῳ' | 'ῑ' | 'ῡ');
+            syllables.push(Syllable {
+                at: i,
+                len: 1,
+                long,
+            });
+            i += 1;
+        }
+    }
+    syllables
+}
+
+// The α and υ of participles in -ας and -υς are long before the σ of their
+// endings, where a ν has dropped out: ἱστᾶσα, δεικνῦσα. ending is the
+// syllable they fall in, the last of the nominative.
+fn lengthen(chars: &[char], syllables: &mut [Syllable], ending: usize) {
+    if let Some(syllable) = syllables.get_mut(ending) {
+        let lengthened = syllable.len == 1
+            && matches!(bare(chars[syllable.at]), 'α' | 'υ')
+            && matches!(chars.get(syllable.at + 1), Some('σ') | Some('ς'));
+        syllable.long |= lengthened;
+    }
+}
+
+pub fn syllable_count(word: &str) -> usize {
+    syllables(&word.chars().collect::<Vec<char>>()).len()
+}
+
+pub fn is_accented(word: &str) -> bool {
+    word.chars().any(|c| {
+        ACUTE.iter().any(|(_, acute)| c == *acute) || CIRCUMFLEX.iter().any(|(_, circ)| c == *circ)
+    })
+}
+
+// The syllable that carries the accent, counted from the start
+pub fn accent_index(word: &str) -> Option<usize> {
+    let chars: Vec<char> = word.chars().collect();
+    syllables(&chars).iter().position(|syllable| {
+        is_accented(
+            &chars[syllable.at..syllable.at + syllable.len]
+                .iter()
+                .collect::<String>(),
+        )
+    })
+}
+
+fn plain(c: char) -> char {
+    ACUTE
+        .iter()
+        .chain(CIRCUMFLEX.iter())
+        .chain(GRAVE.iter())
+        .chain(TYPED.iter())
+        .find(|(_, accented)| c == *accented)
+        .map_or(c, |(plain, _)| *plain)
+}
+
+pub fn unaccented(word: &str) -> String {
+    word.chars().map(|c| short(plain(c))).collect()
+}
+
+// The accent goes on the second letter of a diphthong: παιδεύω, εἶχον
+fn place(chars: &mut [char], syllable: &Syllable, accent: Accent) {
+    let at = syllable.at + syllable.len - 1;
+    chars[at] = short(chars[at]);
+    let table: &[(char, char)] = match accent {
+        Accent::Acute => &ACUTE,
+        Accent::Circumflex => &CIRCUMFLEX,
+    };
+    if let Some((_, accented)) = table.iter().find(|(plain, _)| *plain == chars[at]) {
+        chars[at] = *accented;
+    }
+}
+
+// Final -αι and -οι count as short, except in the optative: the infinitive
+// λῦσαι but the optative λύσαι, παιδεύοι.
+// A final -υ, -υς or -υν in a verb is the long ῡ of the stem: δείκνυς, ἐδείκνυν
+fn final_long(chars: &[char], last: &Syllable, optative: bool) -> bool {
+    if last.len == 2 && last.at + 2 == chars.len() && bare(chars[last.at + 1]) == 'ι' {
+        return match chars[last.at] {
+            'α' | 'ο' => optative,
+            _ => true,
+        };
+    }
+    if last.len == 1 && plain(chars[last.at]) == 'υ' {
+        match &chars[last.at + 1..] {
+            [] | ['ς'] | ['ν'] => return true,
+            _ => (),
+        }
+    }
+    last.long
+}
+
+// The accent on a given syllable, counted from the start, or as near it as
+// the last syllable allows: no further back than the third from the end if
+// the last is short, the second if it is long. A long second-last takes a
+// circumflex when the last is short.
+fn persistent_at(syllables: &[Syllable], at: usize, long: bool) -> (usize, Accent) {
+    let n = syllables.len();
+    let lowest = if long {
+        n.saturating_sub(2)
+    } else {
+        n.saturating_sub(3)
+    };
+    let at = at.min(n - 1).max(lowest);
+    if at + 2 == n && !long && syllables[at].long {
+        (at, Accent::Circumflex)
+    } else {
+        (at, Accent::Acute)
+    }
+}
+
+// The recessive accent goes as far back as it can, but not past floor: in a
+// compound, the augment or the preverb's last syllable
+fn recessive_at(
+    chars: &[char],
+    syllables: &[Syllable],
+    optative: bool,
+    floor: usize,
+) -> (usize, Accent) {
+    let last = &syllables[syllables.len() - 1];
+    match syllables.len() {
+        1 if last.long => (0, Accent::Circumflex),
+        1 => (0, Accent::Acute),
+        _ => persistent_at(syllables, floor, final_long(chars, last, optative)),
+    }
+}
+
+fn accented(word: &str, at: impl Fn(&[char], &[Syllable]) -> (usize, Accent)) -> String {
+    let mut chars: Vec<char> = word.chars().collect();
+    let syllables = syllables(&chars);
+    if !is_accented(word) && !syllables.is_empty() {
+        let (at, accent) = at(&chars, &syllables);
+        place(&mut chars, &syllables[at], accent);
+    }
+    chars.into_iter().map(short).collect()
+}
+
+// Forms already accented are left alone
+pub fn recessive(word: &str, optative: bool, floor: usize) -> String {
+    accented(word, |chars, syllables| {
+        recessive_at(chars, syllables, optative, floor)
+    })
+}
+
+// The syllable a recessive accent would fall on
+pub fn recessive_index(word: &str, floor: usize, lengthened: bool) -> usize {
+    let chars: Vec<char> = word.chars().collect();
+    let mut syllables = syllables(&chars);
+    if syllables.is_empty() {
+        return 0;
+    }
+    if lengthened {
+        let ending = syllables.len() - 1;
+        lengthen(&chars, &mut syllables, ending);
+    }
+    recessive_at(&chars, &syllables, false, floor).0
+}
+
+// Participles and adjectives keep their accent on the syllable it has in the
+// masculine nominative, as far as the last syllable allows: λύων, λυόντων.
+// A feminine -ας is the long accusative plural: λυούσας.
+pub fn persistent(word: &str, at: usize, lengthened: Option<usize>, feminine: bool) -> String {
+    accented(word, |chars, syllables| {
+        let mut syllables = syllables.to_vec();
+        if let Some(ending) = lengthened {
+            lengthen(chars, &mut syllables, ending);
+        }
+        let last = &syllables[syllables.len() - 1];
+        let long = final_long(chars, last, false) || (feminine && word.ends_with("ας"));
+        persistent_at(&syllables, at, long)
+    })
+}
+
+// A long syllable made by contraction, counted from the start, takes the
+// accent: a circumflex wherever the last syllable allows one (διδῶ, διδῶμεν,
+// διδοῖμεν), an acute otherwise (διδώμεθα, διδοίην)
+pub fn on_contraction(word: &str, at: usize, optative: bool) -> String {
+    accented(word, |chars, syllables| {
+        let n = syllables.len();
+        if at + 1 >= n {
+            return (n - 1, Accent::Circumflex);
+        }
+        let long = final_long(chars, &syllables[n - 1], optative);
+        persistent_at(syllables, at, long)
+    })
+}
+
+// -ναι infinitives, the strong aorist middle's -έσθαι and the aorist passive
+// optative keep the accent on the second-last: λυθῆναι, λαβέσθαι, λυθεῖμεν
+pub fn penultimate(word: &str) -> String {
+    accented(word, |chars, syllables| {
+        let long = final_long(chars, &syllables[syllables.len() - 1], false);
+        persistent_at(syllables, syllables.len().saturating_sub(2), long)
+    })
+}
+
+// A circumflex on the last syllable, whatever it had: λυουσῶν, λυτοῦ
+pub fn perispomenon(word: &str) -> String {
+    let mut chars: Vec<char> = unaccented(word).chars().collect();
+    let syllables = syllables(&chars);
+    if let Some(last) = syllables.last() {
+        place(&mut chars, last, Accent::Circumflex);
+    }
+    chars.into_iter().collect()
+}
+
+// A contracted form is accented as it was before contracting, open being the
+// stem vowel and ending as they were and closed what they became. An accent
+// on the first of the two vowels makes a circumflex on the contraction
+// (τιμάω, τιμῶ), one on the second an acute (τιμαέτω, τιμάτω), and one on
+// neither stays on its own syllable (ἐτίμαε, ἐτίμα).
+fn contract(
+    stem: &str,
+    open: &str,
+    closed: &str,
+    at: impl Fn(&[char], &[Syllable]) -> (usize, Accent),
+) -> String {
+    let word = format!("{}{}", stem, closed);
+    let open_chars: Vec<char> = format!("{}{}", stem, open).chars().collect();
+    let open_syllables = syllables(&open_chars);
+    let mut chars: Vec<char> = word.chars().collect();
+    let closed_syllables = syllables(&chars);
+    if open_syllables.is_empty() || open_syllables.len() == closed_syllables.len() {
+        return accented(&word, at);
+    }
+    let contraction = syllable_count(stem);
+    let (accented, accent) = at(&open_chars, &open_syllables);
+    if accented == contraction {
+        place(
+            &mut chars,
+            &closed_syllables[contraction],
+            Accent::Circumflex,
+        );
+    } else if accented == contraction + 1 {
+        place(&mut chars, &closed_syllables[contraction], Accent::Acute);
+    } else if accented < contraction {
+        place(&mut chars, &closed_syllables[accented], accent);
+    } else {
+        place(&mut chars, &closed_syllables[accented - 1], accent);
+    }
+    chars.into_iter().map(short).collect()
+}
+
+pub fn contracted(stem: &str, open: &str, closed: &str, optative: bool) -> String {
+    contract(stem, open, closed, |chars, syllables| {
+        recessive_at(chars, syllables, optative, 0)
+    })
+}
+
+// A contract participle keeps the syllable of its open nominative: τιμάων,
+// τιμάον, so τιμῶν and τιμῶν
+pub fn contracted_at(stem: &str, open: &str, closed: &str, at: usize) -> String {
+    contract(stem, open, closed, |chars, syllables| {
+        let long = final_long(chars, &syllables[syllables.len() - 1], false);
+        persistent_at(syllables, at, long)
+    })
+}
+
+// Where the accent of a participle or adjective sits in its masculine
+// nominative singular
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Nominative {
+    // λύων, λυόμενος; the floor as for the recessive accent
+    Recessive(usize),
+    // λυτέος
+    Penult,
+    // λαβών, λυθείς, λυτός
+    Oxytone,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::participle::{
+        decline, Table, ALPHA_PURE, AORIST_PASSIVE, MIDDLE, THEMATIC, UPSILON_ROOT, WEAK_AORIST,
+    };
+
+    // row, case and the form expected there
+    type Forms = &'static [(usize, usize, &'static str)];
+
+    fn chars(word: &str) -> Vec<char> {
+        word.chars().collect()
+    }
+
+    #[test]
+    fn long_finals() {
+        let cases = [
+            ("λυω", false, true),
+            ("λυομεν", false, false),
+            ("ἐλυσα", false, false),
+            ("λυσαις", false, true),
+            ("λυει", false, true),
+            // -αι and -οι are short but in the optative
+            ("λυσαι", false, false),
+            ("λυσαι", true, true),
+            ("λυοι", false, false),
+            ("λυοι", true, true),
+            // the ῡ of the νυ-class
+            ("δεικνυ", false, true),
+            ("δεικνυς", false, true),
+            ("ἐδεικνυν", false, true),
+        ];
+        for (word, optative, long) in cases.iter() {
+            let chars = chars(word);
+            let last = syllables(&chars).pop().unwrap();
+            assert_eq!(final_long(&chars, &last, *optative), *long, "{}", word);
+        }
+    }
+
+    #[test]
+    fn persistent_syllables() {
+        let cases = [
+            ("λυων", 0, true, (0, Accent::Acute)),
+            ("λυομενου", 1, true, (2, Accent::Acute)),
+            ("λυομενος", 1, false, (1, Accent::Acute)),
+            ("λυουσα", 1, false, (1, Accent::Circumflex)),
+            ("λυοντες", 1, false, (1, Accent::Acute)),
+            ("λυθεις", 1, true, (1, Accent::Acute)),
+        ];
+        for (word, at, long, expected) in cases.iter() {
+            let syllables = syllables(&chars(word));
+            assert_eq!(persistent_at(&syllables, *at, *long), *expected, "{}", word);
+        }
+    }
+
+    #[test]
+    fn recessive_syllables() {
+        let cases = [
+            ("λυω", false, 0, (0, Accent::Acute)),
+            ("ἐλυομεθα", false, 0, (2, Accent::Acute)),
+            ("παιδευσαι", false, 0, (0, Accent::Acute)),
+            ("παιδευσαι", true, 0, (1, Accent::Acute)),
+            ("στη", false, 0, (0, Accent::Circumflex)),
+            ("σχες", false, 0, (0, Accent::Acute)),
+            ("ἀπηγον", false, 1, (1, Accent::Circumflex)),
+        ];
+        for (word, optative, floor, expected) in cases.iter() {
+            let chars = chars(word);
+            let syllables = syllables(&chars);
+            assert_eq!(
+                recessive_at(&chars, &syllables, *optative, *floor),
+                *expected,
+                "{}",
+                word
+            );
+        }
+    }
+
+    #[test]
+    fn recessive_words() {
+        let cases = [
+            ("λυω", false, 0, "λύω"),
+            ("λυομεν", false, 0, "λύομεν"),
+            ("ἐλυομεθα", false, 0, "ἐλυόμεθα"),
+            ("παιδευω", false, 0, "παιδεύω"),
+            ("ἐχε", false, 0, "ἔχε"),
+            ("εἰχον", false, 0, "εἶχον"),
+            ("παιδευσαι", false, 0, "παίδευσαι"),
+            ("παιδευσαι", true, 0, "παιδεύσαι"),
+            ("παιδευοι", true, 0, "παιδεύοι"),
+            ("παιδευοιτο", true, 0, "παιδεύοιτο"),
+            ("δεικνυς", false, 0, "δείκνυς"),
+            ("δεικνυ", false, 0, "δείκνυ"),
+            // ῡ marked long
+            ("λῡσαι", false, 0, "λῦσαι"),
+            ("λῡσον", false, 0, "λῦσον"),
+            ("ἐλῡσα", false, 0, "ἔλυσα"),
+            ("λύω", false, 0, "λύω"),
+        ];
+        for (word, optative, floor, expected) in cases.iter() {
+            assert_eq!(recessive(word, *optative, *floor), *expected);
+        }
+    }
+
+    // The accent goes no further back than the augment, or the preverb's
+    // last syllable
+    #[test]
+    fn compounds() {
+        let cases = [
+            ("ἀπηγον", 1, "ἀπῆγον"),
+            ("ἀπεδωκα", 1, "ἀπέδωκα"),
+            ("παρεσχον", 1, "παρέσχον"),
+            ("ἀποδος", 1, "ἀπόδος"),
+            ("ἀπεχε", 0, "ἄπεχε"),
+            ("συνεξεπεμψα", 2, "συνεξέπεμψα"),
+        ];
+        for (word, floor, expected) in cases.iter() {
+            assert_eq!(recessive(word, false, *floor), *expected);
+        }
+    }
+
+    #[test]
+    fn contractions() {
+        let cases = [
+            ("τιμ", "αω", "ω", false, "τιμῶ"),
+            ("τιμ", "αεις", "ᾳς", false, "τιμᾷς"),
+            ("τιμ", "αετω", "ατω", false, "τιμάτω"),
+            ("ἐτιμ", "αε", "α", false, "ἐτίμα"),
+            ("ποι", "εουσι", "ουσι", false, "ποιοῦσι"),
+            ("ποι", "εομεθα", "ουμεθα", false, "ποιούμεθα"),
+            ("πλ", "εομεν", "εομεν", false, "πλέομεν"),
+            ("δηλ", "οοιην", "οιην", true, "δηλοίην"),
+            ("ἐλ", "αω", "ω", false, "ἐλῶ"),
+        ];
+        for (stem, open, closed, optative, expected) in cases.iter() {
+            assert_eq!(contracted(stem, open, closed, *optative), *expected);
+        }
+        let cases = [
+            ("τιμ", "αων", "ων", 1, "τιμῶν"),
+            ("τιμ", "αοντων", "ωντων", 1, "τιμώντων"),
+            ("ποι", "εουσα", "ουσα", 1, "ποιοῦσα"),
+            ("ζ", "αον", "ων", 0, "ζῶν"),
+        ];
+        for (stem, open, closed, at, expected) in cases.iter() {
+            assert_eq!(contracted_at(stem, open, closed, *at), *expected);
+        }
+    }
+
+    // -μι subjunctives and optatives, and those of root and κ aorists
+    #[test]
+    fn on_contractions() {
+        let cases = [
+            ("διδω", 1, false, "διδῶ"),
+            ("διδῳς", 1, false, "διδῷς"),
+            ("διδωμεθα", 1, false, "διδώμεθα"),
+            ("τιθω", 1, false, "τιθῶ"),
+            ("ἱστωμεν", 1, false, "ἱστῶμεν"),
+            ("δω", 0, false, "δῶ"),
+            ("ἀποδω", 2, false, "ἀποδῶ"),
+            ("ἀποστω", 2, false, "ἀποστῶ"),
+            ("διδοιμεν", 1, true, "διδοῖμεν"),
+            ("διδοιην", 1, true, "διδοίην"),
+            ("τιθειμεν", 1, true, "τιθεῖμεν"),
+            ("ἱσταιμεν", 1, true, "ἱσταῖμεν"),
+            ("ἱειμεν", 1, true, "ἱεῖμεν"),
+            ("ἀποδοιμεν", 2, true, "ἀποδοῖμεν"),
+            ("ἀποσταιμεν", 2, true, "ἀποσταῖμεν"),
+        ];
+        for (word, at, optative, expected) in cases.iter() {
+            assert_eq!(on_contraction(word, *at, *optative), *expected);
+        }
+    }
+
+    #[test]
+    fn fixed() {
+        assert_eq!(penultimate("λυθηναι"), "λυθῆναι");
+        assert_eq!(penultimate("λαβεσθαι"), "λαβέσθαι");
+        assert_eq!(penultimate("λυθειμεν"), "λυθεῖμεν");
+        assert_eq!(perispomenon("λυουσων"), "λυουσῶν");
+        assert_eq!(perispomenon("λυτῳ"), "λυτῷ");
+    }
+
+    #[test]
+    fn participles() {
+        let cases: [(&str, &Table, Nominative, Forms); 9] = [
+            (
+                "λυ",
+                &THEMATIC,
+                Nominative::Recessive(0),
+                &[
+                    (0, 0, "λύων"),
+                    (0, 3, "λύοντος"),
+                    (1, 3, "λυόντων"),
+                    (2, 0, "λύουσα"),
+                    (3, 2, "λυούσας"),
+                    (3, 3, "λυουσῶν"),
+                    (4, 3, "λύοντος"),
+                ],
+            ),
+            (
+                "ἀπαγ",
+                &THEMATIC,
+                Nominative::Recessive(1),
+                &[(0, 0, "ἀπάγων"), (4, 0, "ἀπάγον")],
+            ),
+            (
+                "λυσ",
+                &WEAK_AORIST,
+                Nominative::Recessive(0),
+                &[
+                    (0, 0, "λύσας"),
+                    (1, 3, "λυσάντων"),
+                    (2, 0, "λύσασα"),
+                    (4, 3, "λύσαντος"),
+                ],
+            ),
+            (
+                "λαβ",
+                &THEMATIC,
+                Nominative::Oxytone,
+                &[
+                    (0, 0, "λαβών"),
+                    (2, 0, "λαβοῦσα"),
+                    (4, 0, "λαβόν"),
+                    (0, 3, "λαβόντος"),
+                ],
+            ),
+            (
+                "λυθ",
+                &AORIST_PASSIVE,
+                Nominative::Oxytone,
+                &[
+                    (0, 0, "λυθείς"),
+                    (2, 0, "λυθεῖσα"),
+                    (3, 3, "λυθεισῶν"),
+                    (4, 0, "λυθέν"),
+                ],
+            ),
+            (
+                "δεικν",
+                &UPSILON_ROOT,
+                Nominative::Oxytone,
+                &[(0, 0, "δεικνύς"), (2, 0, "δεικνῦσα"), (4, 0, "δεικνύν")],
+            ),
+            (
+                "λυομεν",
+                &MIDDLE,
+                Nominative::Recessive(0),
+                &[
+                    (0, 0, "λυόμενος"),
+                    (0, 3, "λυομένου"),
+                    (2, 0, "λυομένη"),
+                    (4, 0, "λυόμενον"),
+                ],
+            ),
+            (
+                "λυτε",
+                &ALPHA_PURE,
+                Nominative::Penult,
+                &[(0, 0, "λυτέος"), (2, 0, "λυτέα"), (0, 3, "λυτέου")],
+            ),
+            (
+                "λυτ",
+                &MIDDLE,
+                Nominative::Oxytone,
+                &[
+                    (0, 0, "λυτός"),
+                    (0, 3, "λυτοῦ"),
+                    (2, 0, "λυτή"),
+                    (1, 3, "λυτῶν"),
+                    (4, 0, "λυτόν"),
+                ],
+            ),
+        ];
+        for (stem, table, nominative, expected) in cases.iter() {
+            let mut declined = decline(stem, table);
+            declined.accent(*nominative);
+            let rows = declined.to_vecs().unwrap();
+            for (row, case, form) in expected.iter() {
+                assert_eq!(rows[*row][*case], *form, "{}", stem);
+            }
+        }
+    }
+
+    #[test]
+    fn unaccenting() {
+        assert_eq!(unaccented("παιδεῦσαι"), "παιδευσαι");
+        assert_eq!(unaccented("ἦγον"), "ἠγον");
+        // graves, the oxia acute and an accented diaeresis, as typed in
+        assert_eq!(unaccented("λὺω"), "λυω");
+        assert_eq!(unaccented("λ\u{1f7b}ω"), "λυω");
+        assert_eq!(unaccented("προΐημι"), "προϊημι");
+        assert_eq!(unaccented("ἐλῡσα"), "ἐλυσα");
+        assert!(is_accented("λύω"));
+        assert!(!is_accented("ἀγω"));
+    }
+}
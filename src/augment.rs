@@ -0,0 +1,262 @@
+use crate::funcs::read_unaccented;
+use crate::preverb::{breathe, is_rough, is_vowel, strip_breathing};
+use csv::Reader;
+use serde::Deserialize;
+use std::error::Error;
+use std::io::Read;
+
+// Temporal augment lengthens an initial vowel and keeps its breathing; the ι
+// of a diphthong goes under the long vowel. Diphthongs come first so that αι
+// is not taken for α, and ε before α, which is what an ἠ- is taken back to
+// when nothing says otherwise. Vowels already long, ει and ου, and ι and υ,
+// whose lengthening does not show, stay as they are.
+const LENGTHENED: [(&str, &str); 8] = [
+    ("αι", "ῃ"),
+    ("ευ", "ηυ"),
+    ("αυ", "ηυ"),
+    ("οι", "ῳ"),
+    ("ᾳ", "ῃ"),
+    ("ε", "η"),
+    ("α", "η"),
+    ("ο", "ω"),
+];
+
+// The first n letters of a stem, as a byte length
+fn prefix_len(stem: &str, n: usize) -> usize {
+    stem.char_indices().nth(n).map_or(stem.len(), |(i, _)| i)
+}
+
+// The augment and the part of the stem it goes on. ei is for the verbs
+// which lengthen ε to ει rather than η: ἐχω, εἰχον; ἑπομαι, εἱπομην.
+pub fn apply(stem: &str, ei: bool) -> (String, &str) {
+    let first = match stem.chars().next() {
+        Some(c) => c,
+        None => return (String::new(), stem),
+    };
+    // ῥιπτω, ἐρριψα
+    if first == 'ῥ' || first == 'ρ' {
+        return ("ἐρρ".to_string(), stem.trim_start_matches(['ῥ', 'ρ']));
+    }
+    if !is_vowel(first) {
+        return ("ἐ".to_string(), stem);
+    }
+    let unbreathed = strip_breathing(stem);
+    let rough = is_rough(stem);
+    if ei && unbreathed.starts_with('ε') && !unbreathed.starts_with("ει") {
+        let rest = &stem[prefix_len(stem, 1)..];
+        return (breathe("ει", rough), rest);
+    }
+    for (short, long) in LENGTHENED.iter() {
+        if unbreathed.starts_with(short) {
+            let rest = &stem[prefix_len(stem, short.chars().count())..];
+            return (breathe(long, rough), rest);
+        }
+    }
+    (String::new(), stem)
+}
+
+// Takes the augment off again. A long vowel could have come from more than
+// one short one (ἠ from ἀ or ἐ), so the present decides; without it ε, αι
+// and ευ are assumed.
+pub fn remove(stem: &str, pres: Option<&str>) -> String {
+    let mut chars = stem.chars();
+    let (first, second) = match (chars.next(), chars.next()) {
+        (Some(first), second) => (first, second),
+        (None, _) => return stem.to_string(),
+    };
+    if let Some(rest) = stem.strip_prefix("ἐρρ") {
+        return format!("ῥ{}", rest);
+    }
+    // the syllabic augment, with or without its breathing written
+    if strip_breathing(&first.to_string()) == "ε" && matches!(second, Some(c) if !is_vowel(c)) {
+        return stem[prefix_len(stem, 1)..].to_string();
+    }
+    let unbreathed = strip_breathing(stem);
+    let rough = is_rough(stem);
+    let pres_unbreathed = pres.map(strip_breathing);
+    // εἰχον from ἐχω, εἱλον from αἱρεω; a verb whose present has ει- already
+    // keeps it
+    if unbreathed.starts_with("ει") {
+        return match &pres_unbreathed {
+            Some(p) if p.starts_with("ει") => stem.to_string(),
+            _ => {
+                let rest = &stem[prefix_len(stem, 2)..];
+                format!("{}{}", breathe("ε", rough), rest)
+            }
+        };
+    }
+    // the diphthongs are listed first, so the first match is the longest
+    let candidates: Vec<&(&str, &str)> = LENGTHENED
+        .iter()
+        .filter(|(_, long)| unbreathed.starts_with(long))
+        .collect();
+    let found = candidates
+        .iter()
+        .find(|(short, _)| match &pres_unbreathed {
+            Some(p) => p.starts_with(short),
+            None => false,
+        })
+        .or_else(|| candidates.first());
+    match found {
+        Some((short, long)) => {
+            let rest = &stem[prefix_len(stem, long.chars().count())..];
+            format!("{}{}", breathe(short, rough), rest)
+        }
+        None => stem.to_string(),
+    }
+}
+
+// Augments that no rule gives, as the simple verb's stem before and after.
+// ἐχω, ἑπομαι and ὁραω once began with a consonant, and took ἐ- before it;
+// εἰπον keeps its ει- outside the indicative and εἰδον loses it altogether.
+const IRREGULAR: [(&str, &str); 11] = [
+    ("ἐχ", "εἰχ"),
+    ("ὁρ", "ἑωρ"),
+    ("ἑπ", "εἱπ"),
+    ("ἐθελ", "ἠθελ"),
+    ("ἐ", "εἰ"),
+    ("ἐργαζ", "εἰργαζ"),
+    ("ἑλκ", "εἱλκ"),
+    ("ἑρπ", "εἱρπ"),
+    ("ὠθ", "ἐωθ"),
+    ("εἰπ", "εἰπ"),
+    ("ἰδ", "εἰδ"),
+];
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct Irregular {
+    stem: String,
+    augmented: String,
+}
+
+// Irregular augments, checked before the rules. Whole simple stems are
+// matched, so ἐχ does not catch ἐχθαιρ.
+#[derive(Debug)]
+pub struct Lexicon {
+    entries: Vec<Irregular>,
+}
+
+impl Lexicon {
+    pub fn builtin() -> Lexicon {
+        let entries = IRREGULAR
+            .iter()
+            .map(|(stem, augmented)| Irregular {
+                stem: stem.to_string(),
+                augmented: augmented.to_string(),
+            })
+            .collect();
+        Lexicon { entries }
+    }
+
+    // A CSV file with Stem and Augmented columns; its entries are put ahead
+    // of the built-in ones
+    pub fn load(&mut self, path: &str) -> Result<(), Box<dyn Error>> {
+        let contents = read_unaccented(path)?;
+        self.read(contents.as_bytes())
+    }
+
+    fn read<R: Read>(&mut self, source: R) -> Result<(), Box<dyn Error>> {
+        let mut rdr = Reader::from_reader(source);
+        let mut entries: Vec<Irregular> = Vec::new();
+        for result in rdr.deserialize() {
+            entries.push(result?);
+        }
+        entries.append(&mut self.entries);
+        self.entries = entries;
+        Ok(())
+    }
+
+    pub fn augmented(&self, stem: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|entry| entry.stem == stem)
+            .map(|entry| entry.augmented.as_str())
+    }
+
+    pub fn unaugmented(&self, stem: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|entry| entry.augmented == stem)
+            .map(|entry| entry.stem.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applied() {
+        let cases = [
+            ("λυ", false, "ἐ", "λυ"),
+            ("ῥιπτ", false, "ἐρρ", "ιπτ"),
+            ("ἀγ", false, "ἠ", "γ"),
+            ("ἐλπιζ", false, "ἠ", "λπιζ"),
+            ("ὀρθο", false, "ὠ", "ρθο"),
+            ("αἰτ", false, "ᾐ", "τ"),
+            ("οἰκ", false, "ᾠ", "κ"),
+            ("αὐξ", false, "ηὐ", "ξ"),
+            ("ἁμαρτ", false, "ἡ", "μαρτ"),
+            ("ἐχ", true, "εἰ", "χ"),
+            ("ἱκ", false, "", "ἱκ"),
+        ];
+        for (stem, ei, aug, rest) in cases.iter() {
+            assert_eq!(apply(stem, *ei), (aug.to_string(), *rest), "{}", stem);
+        }
+    }
+
+    #[test]
+    fn removed() {
+        let cases = [
+            ("ἐλυ", None, "λυ"),
+            ("ἐρριψ", None, "ῥιψ"),
+            ("ἠγ", Some("ἀγ"), "ἀγ"),
+            ("ἠγ", None, "ἐγ"),
+            ("ᾐτ", Some("αἰτ"), "αἰτ"),
+            ("ηὐξ", Some("αὐξ"), "αὐξ"),
+            ("ἡμαρτ", Some("ἁμαρτ"), "ἁμαρτ"),
+            ("εἰχ", None, "ἐχ"),
+            ("εἰκαζ", Some("εἰκαζ"), "εἰκαζ"),
+        ];
+        for (stem, pres, expected) in cases.iter() {
+            assert_eq!(remove(stem, *pres), *expected, "{}", stem);
+        }
+    }
+
+    #[test]
+    fn builtin() {
+        let lexicon = Lexicon::builtin();
+        assert_eq!(lexicon.augmented("ἐχ"), Some("εἰχ"));
+        assert_eq!(lexicon.augmented("ὁρ"), Some("ἑωρ"));
+        assert_eq!(lexicon.unaugmented("ἑωρ"), Some("ὁρ"));
+        assert_eq!(lexicon.augmented("ἐχθαιρ"), None);
+    }
+
+    #[test]
+    fn loaded() {
+        let mut lexicon = Lexicon::builtin();
+        let csv = "Stem,Augmented\nὁρ,ὡρ\nἀλισκ,ἑαλισκ\n";
+        lexicon.read(csv.as_bytes()).unwrap();
+        assert_eq!(lexicon.augmented("ὁρ"), Some("ὡρ"));
+        assert_eq!(lexicon.unaugmented("ἑαλισκ"), Some("ἀλισκ"));
+        assert_eq!(lexicon.augmented("ἐχ"), Some("εἰχ"));
+    }
+
+    #[test]
+    fn bad_header() {
+        let csv = "Present,Imperfect\nἐχ,εἰχ\n";
+        assert!(Lexicon::builtin().read(csv.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn loaded_accented() {
+        let path = std::env::temp_dir().join(format!("augments-{}.csv", std::process::id()));
+        std::fs::write(&path, "Stem,Augmented\nὁρ,ἑώρ\n").unwrap();
+        let mut lexicon = Lexicon::builtin();
+        let result = lexicon.load(path.to_str().unwrap());
+        std::fs::remove_file(&path).unwrap();
+        result.unwrap();
+        assert_eq!(lexicon.augmented("ὁρ"), Some("ἑωρ"));
+    }
+}
@@ -24,4 +24,57 @@ pub fn clap_app() -> clap::App<'static, 'static> {
                 .long("print")
                 .takes_value(false),
         )
+        .arg(
+            Arg::with_name("dual")
+                .help("Include 2nd and 3rd person dual forms")
+                .short("d")
+                .long("dual")
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name("participles")
+                .help("Append declined participles after the finite forms")
+                .short("P")
+                .long("participles")
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name("verbal")
+                .help("Append the verbal adjectives in -τεος and -τος")
+                .short("v")
+                .long("verbal-adjectives")
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name("nu")
+                .help("Movable nu on 3rd person -σι and -ε forms: never, always or paren")
+                .short("n")
+                .long("nu")
+                .takes_value(true)
+                .possible_values(&["never", "always", "paren"])
+                .default_value("never"),
+        )
+        .arg(
+            Arg::with_name("dialect")
+                .help("Dialect to conjugate in: attic, homeric or koine")
+                .short("D")
+                .long("dialect")
+                .takes_value(true)
+                .possible_values(&["attic", "homeric", "koine"])
+                .default_value("attic"),
+        )
+        .arg(
+            Arg::with_name("unaugmented")
+                .help("Leave past tenses unaugmented, as Homer often does")
+                .short("u")
+                .long("unaugmented")
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name("augments")
+                .help("CSV file of irregular augments, with Stem and Augmented columns")
+                .short("a")
+                .long("augments")
+                .takes_value(true),
+        )
 }
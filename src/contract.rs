@@ -0,0 +1,345 @@
+use crate::accent;
+use crate::participle::{Declined, Table};
+use crate::types::TenseVoiceMoodVariant;
+
+// Contract verbs and contracted futures take the ordinary thematic endings,
+// which are then run together with the stem vowel. Each class below says
+// what that vowel makes of the ending's first vowel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Contraction {
+    // τιμαω
+    Alpha,
+    // ζαω, χραομαι: α with ε or ει gives η, ῃ
+    Eta,
+    // ποιεω, and liquid and Attic futures such as ἀγγελω
+    Epsilon,
+    // πλεω: the stem is a single syllable, so only ε and ει contract
+    Open,
+    // δηλοω
+    Omicron,
+    // Homer's φιλεω, φιλεεις: the stem vowel is kept and nothing contracts
+    Uncontracted(&'static str),
+}
+
+// Stems are given without the contract vowel. The longer ones are matched
+// in compounds too (ἐκπλεω, ἀποχραομαι); ζ, θ, δ and the like only on their
+// own, or ποθεω would be caught.
+const ETA_STEMS: [&str; 1] = ["ζ"];
+const ETA_COMPOUNDS: [&str; 3] = ["χρ", "διψ", "πειν"];
+const OPEN_STEMS: [&str; 4] = ["θ", "ῥ", "δ", "χ"];
+const OPEN_COMPOUNDS: [&str; 2] = ["πλ", "πν"];
+// ἐλαυνω has an Attic future in -αω, ἐλω, ἐλᾳς
+const ALPHA_FUTURES: [&str; 1] = ["ἐλ"];
+
+fn listed(stm: &str, stems: &[&str], compounds: &[&str]) -> bool {
+    stems.contains(&stm) || compounds.iter().any(|cpd| stm.ends_with(cpd))
+}
+
+impl Contraction {
+    pub fn of(stmtype: &TenseVoiceMoodVariant) -> Contraction {
+        match stmtype {
+            TenseVoiceMoodVariant::PresActIndAlp(stm)
+            | TenseVoiceMoodVariant::PresPassIndAlp(stm) => {
+                if listed(stm, &ETA_STEMS, &ETA_COMPOUNDS) {
+                    Contraction::Eta
+                } else {
+                    Contraction::Alpha
+                }
+            }
+            TenseVoiceMoodVariant::PresActIndEps(stm)
+            | TenseVoiceMoodVariant::PresPassIndEps(stm) => {
+                if listed(stm, &OPEN_STEMS, &OPEN_COMPOUNDS) {
+                    Contraction::Open
+                } else {
+                    Contraction::Epsilon
+                }
+            }
+            TenseVoiceMoodVariant::PresActIndOmi(_) | TenseVoiceMoodVariant::PresPassIndOmi(_) => {
+                Contraction::Omicron
+            }
+            TenseVoiceMoodVariant::FutActIndEps(stm) | TenseVoiceMoodVariant::FutMidIndEps(stm)
+                if ALPHA_FUTURES.contains(&stm.as_str()) =>
+            {
+                Contraction::Alpha
+            }
+            _ => Contraction::Epsilon,
+        }
+    }
+
+    // The vowel the stem ends in before contraction
+    pub fn vowel(self) -> &'static str {
+        match self {
+            Contraction::Alpha | Contraction::Eta => "α",
+            Contraction::Epsilon | Contraction::Open => "ε",
+            Contraction::Omicron => "ο",
+            Contraction::Uncontracted(vowel) => vowel,
+        }
+    }
+
+    pub fn uncontracted(self) -> Contraction {
+        Contraction::Uncontracted(self.vowel())
+    }
+
+    // Whether the optative keeps the thematic -οιμι, as πλεοιμι and Homer's
+    // φιλεοιμι do, rather than taking -οιην
+    pub fn thematic_optative(self) -> bool {
+        matches!(self, Contraction::Open | Contraction::Uncontracted(_))
+    }
+
+    // What the stem vowel and the ending's first vowel or diphthong become
+    fn combine(self, first: &str) -> Option<&'static str> {
+        let contracted = match (self, first) {
+            (Contraction::Open, "ε") | (Contraction::Open, "ει") => "ει",
+            (Contraction::Open, _) => return None,
+            (_, "ω") => "ω",
+            (Contraction::Alpha, "ε") | (Contraction::Alpha, "η") => "α",
+            (Contraction::Alpha, "ει") | (Contraction::Alpha, "ῃ") => "ᾳ",
+            (Contraction::Eta, "ε") | (Contraction::Eta, "η") => "η",
+            (Contraction::Eta, "ει") | (Contraction::Eta, "ῃ") => "ῃ",
+            (Contraction::Alpha, "ο")
+            | (Contraction::Alpha, "ου")
+            | (Contraction::Eta, "ο")
+            | (Contraction::Eta, "ου") => "ω",
+            (Contraction::Alpha, "οι") | (Contraction::Eta, "οι") => "ῳ",
+            (Contraction::Epsilon, "ε") | (Contraction::Epsilon, "ει") => "ει",
+            (Contraction::Epsilon, "ο") | (Contraction::Epsilon, "ου") => "ου",
+            (Contraction::Epsilon, "η") => "η",
+            (Contraction::Epsilon, "ῃ") => "ῃ",
+            (Contraction::Epsilon, "οι") => "οι",
+            (Contraction::Omicron, "ε")
+            | (Contraction::Omicron, "ο")
+            | (Contraction::Omicron, "ου") => "ου",
+            (Contraction::Omicron, "ει")
+            | (Contraction::Omicron, "ῃ")
+            | (Contraction::Omicron, "οι") => "οι",
+            (Contraction::Omicron, "η") => "ω",
+            _ => return None,
+        };
+        Some(contracted)
+    }
+
+    // The thematic infinitive -ειν is ε + εν, and its ει is spurious: it
+    // contracts as ε does, giving τιμαν and δηλουν rather than τιμᾳν and
+    // δηλοιν. Pass "εν" for the infinitive.
+    pub fn ending(self, ending: &str) -> String {
+        if let Contraction::Uncontracted(vowel) = self {
+            let ending = if ending == "εν" { "ειν" } else { ending };
+            return format!("{}{}", vowel, ending);
+        }
+        let mut chars = ending.chars();
+        let first = match (chars.next(), chars.next()) {
+            (Some(a), Some(b)) if (a == 'ε' || a == 'ο') && (b == 'ι' || b == 'υ') => {
+                ending.split_at(a.len_utf8() + b.len_utf8())
+            }
+            (Some(a), _) => ending.split_at(a.len_utf8()),
+            _ => return self.vowel().to_string(),
+        };
+        match self.combine(first.0) {
+            Some(contracted) => format!("{}{}", contracted, first.1),
+            None => format!("{}{}", self.vowel(), ending),
+        }
+    }
+
+    // The whole form, accented from its open form; the only endings in οι
+    // are the optative's, whose final -οι is long. Homer's open forms are
+    // left for the recessive accent.
+    pub fn form(self, stem: &str, ending: &str) -> String {
+        if let Contraction::Uncontracted(_) = self {
+            return format!("{}{}", stem, self.ending(ending));
+        }
+        let open = if ending == "εν" { "ειν" } else { ending };
+        let open = format!("{}{}", self.vowel(), open);
+        accent::contracted(stem, &open, &self.ending(ending), ending.starts_with("οι"))
+    }
+
+    pub fn forms(self, stem: &str, endings: &[&str]) -> Vec<String> {
+        endings
+            .iter()
+            .map(|ending| self.form(stem, ending))
+            .collect()
+    }
+
+    // ζων, ποιουσα, δηλουν from the thematic -ων, -ουσα, -ον, each accented
+    // on the syllable of the open masculine nominative: τιμάων, so τιμῶν in
+    // the neuter as well
+    pub fn decline(self, stem: &str, table: &Table) -> Declined {
+        let nominative = format!("{}{}{}", stem, self.vowel(), table[0][0]);
+        let at = accent::recessive_index(&nominative, 0, false);
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        for endings in table.iter() {
+            let mut forms: Vec<String> = Vec::new();
+            for ending in endings.iter() {
+                forms.push(match self {
+                    Contraction::Uncontracted(_) => self.form(stem, ending),
+                    _ => {
+                        let open = format!("{}{}", self.vowel(), ending);
+                        accent::contracted_at(stem, &open, &self.ending(ending), at)
+                    }
+                });
+            }
+            rows.push(forms);
+        }
+        Declined::Some(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::participle::THEMATIC;
+
+    const PRESENT: [&str; 6] = ["ω", "εις", "ει", "ομεν", "ετε", "ουσι"];
+
+    #[test]
+    fn classes() {
+        let cases = [
+            (
+                TenseVoiceMoodVariant::PresActIndAlp("τιμ".to_string()),
+                Contraction::Alpha,
+            ),
+            (
+                TenseVoiceMoodVariant::PresActIndAlp("ζ".to_string()),
+                Contraction::Eta,
+            ),
+            (
+                TenseVoiceMoodVariant::PresPassIndAlp("χρ".to_string()),
+                Contraction::Eta,
+            ),
+            (
+                TenseVoiceMoodVariant::PresActIndEps("ποι".to_string()),
+                Contraction::Epsilon,
+            ),
+            (
+                TenseVoiceMoodVariant::PresActIndEps("πλ".to_string()),
+                Contraction::Open,
+            ),
+            (
+                TenseVoiceMoodVariant::PresActIndEps("ἐκπλ".to_string()),
+                Contraction::Open,
+            ),
+            (
+                TenseVoiceMoodVariant::PresActIndEps("ποθ".to_string()),
+                Contraction::Epsilon,
+            ),
+            (
+                TenseVoiceMoodVariant::PresActIndOmi("δηλ".to_string()),
+                Contraction::Omicron,
+            ),
+            (
+                TenseVoiceMoodVariant::FutActIndEps("ἐλ".to_string()),
+                Contraction::Alpha,
+            ),
+            (
+                TenseVoiceMoodVariant::FutActIndEps("ἀγγελ".to_string()),
+                Contraction::Epsilon,
+            ),
+        ];
+        for (stmtype, class) in cases.iter() {
+            assert_eq!(Contraction::of(stmtype), *class, "{}", stmtype);
+        }
+    }
+
+    #[test]
+    fn presents() {
+        let cases = [
+            (
+                Contraction::Alpha,
+                "τιμ",
+                ["τιμῶ", "τιμᾷς", "τιμᾷ", "τιμῶμεν", "τιμᾶτε", "τιμῶσι"],
+            ),
+            (
+                Contraction::Eta,
+                "ζ",
+                ["ζῶ", "ζῇς", "ζῇ", "ζῶμεν", "ζῆτε", "ζῶσι"],
+            ),
+            (
+                Contraction::Epsilon,
+                "ποι",
+                ["ποιῶ", "ποιεῖς", "ποιεῖ", "ποιοῦμεν", "ποιεῖτε", "ποιοῦσι"],
+            ),
+            (
+                Contraction::Open,
+                "πλ",
+                ["πλέω", "πλεῖς", "πλεῖ", "πλέομεν", "πλεῖτε", "πλέουσι"],
+            ),
+            (
+                Contraction::Omicron,
+                "δηλ",
+                ["δηλῶ", "δηλοῖς", "δηλοῖ", "δηλοῦμεν", "δηλοῦτε", "δηλοῦσι"],
+            ),
+            (
+                Contraction::Alpha,
+                "ἐλ",
+                ["ἐλῶ", "ἐλᾷς", "ἐλᾷ", "ἐλῶμεν", "ἐλᾶτε", "ἐλῶσι"],
+            ),
+        ];
+        for (class, stem, expected) in cases.iter() {
+            assert_eq!(class.forms(stem, &PRESENT), expected);
+        }
+    }
+
+    #[test]
+    fn middles() {
+        assert_eq!(
+            Contraction::Eta.forms("χρ", &["ομαι", "ῃ", "εται", "εσθαι"]),
+            ["χρῶμαι", "χρῇ", "χρῆται", "χρῆσθαι"]
+        );
+    }
+
+    #[test]
+    fn infinitives() {
+        let cases = [
+            (Contraction::Alpha, "τιμ", "τιμᾶν"),
+            (Contraction::Eta, "ζ", "ζῆν"),
+            (Contraction::Epsilon, "ποι", "ποιεῖν"),
+            (Contraction::Open, "πλ", "πλεῖν"),
+            (Contraction::Omicron, "δηλ", "δηλοῦν"),
+        ];
+        for (class, stem, expected) in cases.iter() {
+            assert_eq!(class.forms(stem, &["εν"]), [*expected]);
+        }
+    }
+
+    #[test]
+    fn optatives() {
+        let cases = [
+            (Contraction::Alpha, "τιμ", "οιην", "τιμῴην"),
+            (Contraction::Epsilon, "ποι", "οιην", "ποιοίην"),
+            (Contraction::Omicron, "δηλ", "οιην", "δηλοίην"),
+            (Contraction::Open, "πλ", "οιμι", "πλέοιμι"),
+        ];
+        for (class, stem, ending, expected) in cases.iter() {
+            assert_eq!(class.forms(stem, &[ending]), [*expected]);
+        }
+    }
+
+    #[test]
+    fn participles() {
+        let cases = [
+            (Contraction::Alpha, "τιμ", "τιμῶν", "τιμῶσα"),
+            (Contraction::Eta, "ζ", "ζῶν", "ζῶσα"),
+            (Contraction::Epsilon, "ποι", "ποιῶν", "ποιοῦσα"),
+            (Contraction::Omicron, "δηλ", "δηλῶν", "δηλοῦσα"),
+        ];
+        for (class, stem, masculine, feminine) in cases.iter() {
+            match class.decline(stem, &THEMATIC) {
+                Declined::Some(rows) => {
+                    assert_eq!(rows[0][0], *masculine);
+                    assert_eq!(rows[2][0], *feminine);
+                }
+                Declined::None => panic!("{} not declined", stem),
+            }
+        }
+    }
+
+    // Homer's φιλεω, φιλεεις
+    #[test]
+    fn uncontracted() {
+        let homeric = Contraction::Epsilon.uncontracted();
+        assert_eq!(homeric.forms("φιλ", &["ω", "εις"]), ["φιλεω", "φιλεεις"]);
+        assert_eq!(homeric.forms("φιλ", &["εν"]), ["φιλεειν"]);
+        assert!(homeric.thematic_optative());
+        assert!(Contraction::Open.thematic_optative());
+        assert!(!Contraction::Alpha.thematic_optative());
+    }
+}
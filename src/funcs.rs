@@ -1,34 +1,46 @@
+use crate::accent::unaccented;
+use crate::augment::Lexicon;
+use crate::irregular;
+use crate::types::{Dialect, MovableNu};
 use crate::verbentry::{Verb, VerbAlternates, VerbEntry, VerbStemSet};
 use csv::Reader;
-use regex::Regex;
 use std::error::Error;
 use std::fs;
 
-pub fn check_file(path: &str) -> bool {
-    let contents = fs::read_to_string(path).expect(&format!("Unable to read {}.", path));
-    // unshifted = accents: ; = acute e.g. ά ' = grave e.g. ὰ
-    // shifted = breathings: : = smooth e.g. ἀ @ = rough e.g. ἁ
-    // you must press the unshifted key first!
-    // ] = iota subscript [ = circumflex. If both, ][
-    let re = Regex::new(r"[άἄἅὰἂἃᾴᾲᾷᾶέὲἔἒἕἓήἤἥὴἢἣῆῄῂῇίἴἵὶἲἳόὄὅὸὂὃώὤὥὼὣὢῶῴῲῷύὔὕὺὒὓ]").unwrap();
-    if re.is_match(&contents) {
-        false
-    } else {
-        true
+// The principal parts may be typed in with or without accents; they are
+// taken off here, and every form is accented again once it is made. The few
+// parts told apart by their accent alone keep it.
+pub fn read_unaccented(path: &str) -> Result<String, Box<dyn Error>> {
+    let contents = fs::read_to_string(path)?;
+    let mut plain = String::with_capacity(contents.len());
+    for field in contents.split_inclusive(&[',', '\n'][..]) {
+        let part = field.trim_end_matches(&[',', '\n', '\r'][..]);
+        if irregular::ACCENTED.contains(&part.trim()) {
+            plain.push_str(field);
+        } else {
+            plain.push_str(&unaccented(field));
+        }
     }
+    Ok(plain)
 }
 
-pub fn parse_file(path: &str) -> Result<Vec<Verb>, Box<dyn Error>> {
-    let mut rdr = Reader::from_path(path)?;
+pub fn parse_file(
+    path: &str,
+    nu: MovableNu,
+    lexicon: &Lexicon,
+    dialect: Dialect,
+) -> Result<Vec<Verb>, Box<dyn Error>> {
+    let contents = read_unaccented(path)?;
+    let mut rdr = Reader::from_reader(contents.as_bytes());
     let mut entries: Vec<Verb> = Vec::new();
     for result in rdr.deserialize() {
         let verb_entry: VerbEntry = result?;
         // println!("Read: {:?}", verb_entry);
         let verb_alternates: VerbAlternates = verb_entry.check_alternates();
         // println!("With alts: {:?}", verb_alternates);
-        let verb_stem_set: VerbStemSet = verb_alternates.parse();
+        let verb_stem_set: VerbStemSet = verb_alternates.parse(lexicon, dialect);
         // println!("Test: {:?}", verb_stem_set);
-        let verb: Verb = verb_stem_set.conjugate();
+        let verb: Verb = verb_stem_set.conjugate(nu);
         // println!("Conjugated: {:?}", verb);
         entries.push(verb);
     }
@@ -0,0 +1,236 @@
+use crate::accent::unaccented;
+use crate::participle::{Table, EIMI, MIDDLE, PERFECT, THEMATIC, WEAK_AORIST};
+
+// Verbs too irregular to build from their principal parts, spelled out in
+// full. Forms run 1s, 2s, 3s, 1p, 2p, 3p, 2d, 3d; imperatives 2s, 3s, 2p,
+// 3p, 2d, 3d.
+type Forms = [&'static str; 8];
+
+pub struct Present {
+    pub principal_part: &'static str,
+    pub indicative: Forms,
+    pub imperfect: Forms,
+    pub subjunctive: Forms,
+    pub optative: Forms,
+    pub imperative: [&'static str; 6],
+    pub infinitive: &'static str,
+    pub participle: (&'static str, &'static Table),
+}
+
+pub struct Future {
+    pub principal_part: &'static str,
+    // ἐσομαι takes the middle cells, εἰμι the active ones
+    pub middle: bool,
+    pub indicative: Forms,
+    pub optative: Forms,
+    pub infinitive: &'static str,
+    pub participle: (&'static str, &'static Table),
+}
+
+// Accents are taken off what is read in, but not off these, which only their
+// accent tells apart: εἰμί "be" and εἶμι "go"
+pub const ACCENTED: [&str; 2] = ["εἰμί", "εἶμι"];
+
+// Without its accent εἰμι is taken as "be" in the Present column; in the
+// Future column it is always "go", the future of ἐρχομαι.
+pub static PRESENTS: [Present; 4] = [
+    Present {
+        principal_part: "εἰμι",
+        // enclitic, and so written with the accent the recessive rule would
+        // not give
+        indicative: [
+            "εἰμί",
+            "εἶ",
+            "ἐστί",
+            "ἐσμέν",
+            "ἐστέ",
+            "εἰσί",
+            "ἐστόν",
+            "ἐστόν",
+        ],
+        imperfect: ["ἠ", "ἠσθα", "ἠν", "ἠμεν", "ἠτε", "ἠσαν", "ἠστον", "ἠστην"],
+        subjunctive: ["ὠ", "ᾐς", "ᾐ", "ὠμεν", "ἠτε", "ὠσι", "ἠτον", "ἠτον"],
+        optative: [
+            "εἰην",
+            "εἰης",
+            "εἰη",
+            "εἰμεν",
+            "εἰτε",
+            "εἰεν",
+            "εἰτον",
+            "εἰτην",
+        ],
+        imperative: ["ἰσθι", "ἐστω", "ἐστε", "ἐστων", "ἐστον", "ἐστων"],
+        infinitive: "εἰναι",
+        participle: ("", &EIMI),
+    },
+    // εἶμι "go"; the imperfect is Attic ᾖα rather than ᾔειν
+    Present {
+        principal_part: "εἶμι",
+        indicative: ["εἰμι", "εἰ", "εἰσι", "ἰμεν", "ἰτε", "ἰασι", "ἰτον", "ἰτον"],
+        imperfect: ["ᾐα", "ᾐεισθα", "ᾐει", "ᾐμεν", "ᾐτε", "ᾐσαν", "ᾐτον", "ᾐτην"],
+        subjunctive: ["ἰω", "ἰῃς", "ἰῃ", "ἰωμεν", "ἰητε", "ἰωσι", "ἰητον", "ἰητον"],
+        optative: [
+            "ἰοιμι",
+            "ἰοις",
+            "ἰοι",
+            "ἰοιμεν",
+            "ἰοιτε",
+            "ἰοιεν",
+            "ἰοιτον",
+            "ἰοιτην",
+        ],
+        imperative: ["ἰθι", "ἰτω", "ἰτε", "ἰοντων", "ἰτον", "ἰτων"],
+        infinitive: "ἰέναι",
+        participle: ("ἰ", &THEMATIC),
+    },
+    // a perfect in form, so its pluperfect serves as the imperfect
+    Present {
+        principal_part: "οἰδα",
+        indicative: [
+            "οἰδα",
+            "οἰσθα",
+            "οἰδε",
+            "ἰσμεν",
+            "ἰστε",
+            "ἰσασι",
+            "ἰστον",
+            "ἰστον",
+        ],
+        imperfect: [
+            "ᾐδη",
+            "ᾐδησθα",
+            "ᾐδει",
+            "ᾐσμεν",
+            "ᾐστε",
+            "ᾐσαν",
+            "ᾐστον",
+            "ᾐστην",
+        ],
+        subjunctive: [
+            "εἰδω",
+            "εἰδῃς",
+            "εἰδῃ",
+            "εἰδωμεν",
+            "εἰδητε",
+            "εἰδωσι",
+            "εἰδητον",
+            "εἰδητον",
+        ],
+        optative: [
+            "εἰδειην",
+            "εἰδειης",
+            "εἰδειη",
+            "εἰδειμεν",
+            "εἰδειτε",
+            "εἰδειεν",
+            "εἰδειτον",
+            "εἰδειτην",
+        ],
+        imperative: ["ἰσθι", "ἰστω", "ἰστε", "ἰστων", "ἰστον", "ἰστων"],
+        // accented on the -ε- as -ναι infinitives are
+        infinitive: "εἰδέναι",
+        participle: ("εἰδ", &PERFECT),
+    },
+    Present {
+        principal_part: "φημι",
+        // enclitic like εἰμι
+        indicative: [
+            "φημί",
+            "φῄς",
+            "φησί",
+            "φαμέν",
+            "φατέ",
+            "φασί",
+            "φατόν",
+            "φατόν",
+        ],
+        imperfect: [
+            "ἐφην",
+            "ἐφησθα",
+            "ἐφη",
+            "ἐφαμεν",
+            "ἐφατε",
+            "ἐφασαν",
+            "ἐφατον",
+            "ἐφατην",
+        ],
+        subjunctive: ["φω", "φῃς", "φῃ", "φωμεν", "φητε", "φωσι", "φητον", "φητον"],
+        optative: [
+            "φαιην",
+            "φαιης",
+            "φαιη",
+            "φαιμεν",
+            "φαιτε",
+            "φαιεν",
+            "φαιτον",
+            "φαιτην",
+        ],
+        imperative: ["φαθι", "φατω", "φατε", "φαντων", "φατον", "φατων"],
+        infinitive: "φαναι",
+        participle: ("φ", &WEAK_AORIST),
+    },
+];
+
+pub static FUTURES: [Future; 2] = [
+    Future {
+        principal_part: "ἐσομαι",
+        middle: true,
+        indicative: [
+            "ἐσομαι",
+            "ἐσῃ",
+            "ἐσται",
+            "ἐσομεθα",
+            "ἐσεσθε",
+            "ἐσονται",
+            "ἐσεσθον",
+            "ἐσεσθον",
+        ],
+        optative: [
+            "ἐσοιμην",
+            "ἐσοιο",
+            "ἐσοιτο",
+            "ἐσοιμεθα",
+            "ἐσοισθε",
+            "ἐσοιντο",
+            "ἐσοισθον",
+            "ἐσοισθην",
+        ],
+        infinitive: "ἐσεσθαι",
+        participle: ("ἐσομεν", &MIDDLE),
+    },
+    // εἰμι "go" is present in form but future in sense
+    Future {
+        principal_part: "εἰμι",
+        middle: false,
+        indicative: ["εἰμι", "εἰ", "εἰσι", "ἰμεν", "ἰτε", "ἰασι", "ἰτον", "ἰτον"],
+        optative: [
+            "ἰοιμι",
+            "ἰοις",
+            "ἰοι",
+            "ἰοιμεν",
+            "ἰοιτε",
+            "ἰοιεν",
+            "ἰοιτον",
+            "ἰοιτην",
+        ],
+        // as εἰδέναι
+        infinitive: "ἰέναι",
+        participle: ("ἰ", &THEMATIC),
+    },
+];
+
+// An accented principal part is matched as it is first, so that εἶμι is not
+// taken for εἰμι
+pub fn find_present(pp: &str) -> Option<&'static Present> {
+    let plain = unaccented(pp);
+    PRESENTS
+        .iter()
+        .find(|irr| irr.principal_part == pp)
+        .or_else(|| PRESENTS.iter().find(|irr| irr.principal_part == plain))
+}
+
+pub fn find_future(pp: &str) -> Option<&'static Future> {
+    let plain = unaccented(pp);
+    FUTURES.iter().find(|irr| irr.principal_part == plain)
+}
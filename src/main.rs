@@ -1,9 +1,17 @@
+mod accent;
+mod augment;
 mod clap;
+mod contract;
 mod funcs;
+mod irregular;
+mod participle;
+mod preverb;
 mod types;
 mod verbentry;
-use crate::funcs::{check_file, parse_file};
-use csv::Writer;
+use crate::augment::Lexicon;
+use crate::funcs::parse_file;
+use crate::types::{Deponency, Dialect, MovableNu};
+use csv::WriterBuilder;
 use std::error::Error;
 use std::process;
 
@@ -20,81 +28,85 @@ fn main() -> Result<(), Box<dyn Error>> {
         .value_of("infile")
         .expect("No input file given!");
 
-    if !check_file(infile) {
-        eprintln!("File {} contains accents. Please remove them.", infile);
+    let nu = match cli_matches.value_of("nu") {
+        Some("always") => MovableNu::Always,
+        Some("paren") => MovableNu::Parenthesised,
+        _ => MovableNu::Never,
+    };
+
+    let augment = !cli_matches.is_present("unaugmented");
+    let dialect = match cli_matches.value_of("dialect") {
+        Some("homeric") => Dialect::Homeric { augment },
+        Some("koine") => Dialect::Koine,
+        _ => Dialect::Attic,
+    };
+    if !matches!(dialect, Dialect::Homeric { .. }) && !augment {
+        eprintln!("Only Homeric forms can be left unaugmented.");
         process::exit(1);
     }
 
-    if let Ok(entries) = parse_file(infile) {
+    let mut lexicon = Lexicon::builtin();
+    if let Some(augments) = cli_matches.value_of("augments") {
+        lexicon.load(augments)?;
+    }
+
+    if let Ok(entries) = parse_file(infile, nu, &lexicon, dialect) {
+        let participles = cli_matches.is_present("participles");
+        let dual = cli_matches.is_present("dual");
+        let verbal = cli_matches.is_present("verbal");
         if cli_matches.is_present("print") {
-            for verb in entries {
-                verb.pai.print();
-                verb.ppi.print();
-                verb.iai.print();
-                verb.ipi.print();
-                verb.fai.print();
-                verb.fai2.print();
-                verb.fmi.print();
-                verb.fmi2.print();
-                verb.fpi.print();
-                verb.fpi2.print();
-                verb.aai.print();
-                verb.aai2.print();
-                verb.ami.print();
-                verb.ami2.print();
-                verb.api.print();
-                verb.api2.print();
-            }
-        } else if let Some(outfile) = cli_matches.value_of("outfile") {
-            let mut wtr = Writer::from_path(outfile).expect("Could not create outfile.");
-            for verb in entries {
-                if let Some(conj) = verb.pai.to_vec() {
-                    wtr.write_record(conj)?;
-                }
-                if let Some(conj) = verb.ppi.to_vec() {
-                    wtr.write_record(conj)?;
-                }
-                if let Some(conj) = verb.iai.to_vec() {
-                    wtr.write_record(conj)?;
-                }
-                if let Some(conj) = verb.ipi.to_vec() {
-                    wtr.write_record(conj)?;
-                }
-                if let Some(conj) = verb.fai.to_vec() {
-                    wtr.write_record(conj)?;
-                }
-                if let Some(conj) = verb.fai2.to_vec() {
-                    wtr.write_record(conj)?;
-                }
-                if let Some(conj) = verb.fmi.to_vec() {
-                    wtr.write_record(conj)?;
-                }
-                if let Some(conj) = verb.fmi2.to_vec() {
-                    wtr.write_record(conj)?;
+            for verb in &entries {
+                for (conj, note) in verb.conjugations() {
+                    conj.print(dual, note);
                 }
-                if let Some(conj) = verb.fpi.to_vec() {
-                    wtr.write_record(conj)?;
-                }
-                if let Some(conj) = verb.fpi2.to_vec() {
-                    wtr.write_record(conj)?;
-                }
-                if let Some(conj) = verb.aai.to_vec() {
-                    wtr.write_record(conj)?;
-                }
-                if let Some(conj) = verb.aai2.to_vec() {
-                    wtr.write_record(conj)?;
+            }
+            if participles {
+                for verb in &entries {
+                    for (part, note) in verb.participles() {
+                        part.print(note);
+                    }
                 }
-                if let Some(conj) = verb.ami.to_vec() {
-                    wtr.write_record(conj)?;
+            }
+            if verbal {
+                for verb in &entries {
+                    for adj in verb.verbal_adjectives() {
+                        adj.print(Deponency::None);
+                    }
                 }
-                if let Some(conj) = verb.ami2.to_vec() {
-                    wtr.write_record(conj)?;
+            }
+        } else if let Some(outfile) = cli_matches.value_of("outfile") {
+            // infinitives are single-field rows
+            let mut wtr = WriterBuilder::new()
+                .flexible(true)
+                .from_path(outfile)
+                .expect("Could not create outfile.");
+            for verb in &entries {
+                for (conj, note) in verb.conjugations() {
+                    if let Some(conj) = conj.to_vec(dual) {
+                        wtr.write_record(labelled(conj, note))?;
+                    }
                 }
-                if let Some(conj) = verb.api.to_vec() {
-                    wtr.write_record(conj)?;
+            }
+            if participles {
+                for verb in &entries {
+                    for (part, note) in verb.participles() {
+                        if let Some(rows) = part.to_vecs() {
+                            for row in rows {
+                                wtr.write_record(labelled(row, note))?;
+                            }
+                        }
+                    }
                 }
-                if let Some(conj) = verb.api2.to_vec() {
-                    wtr.write_record(conj)?;
+            }
+            if verbal {
+                for verb in &entries {
+                    for adj in verb.verbal_adjectives() {
+                        if let Some(rows) = adj.to_vecs() {
+                            for row in rows {
+                                wtr.write_record(row)?;
+                            }
+                        }
+                    }
                 }
             }
             wtr.flush()?;
@@ -102,3 +114,12 @@ fn main() -> Result<(), Box<dyn Error>> {
     }
     Ok(())
 }
+
+// deponent rows carry their label in an extra trailing field
+fn labelled(row: &[String], note: Deponency) -> Vec<String> {
+    let mut record = row.to_vec();
+    if note != Deponency::None {
+        record.push(note.to_string());
+    }
+    record
+}
@@ -0,0 +1,206 @@
+use crate::accent::{
+    accent_index, perispomenon, persistent, recessive_index, syllable_count, unaccented, Nominative,
+};
+use crate::types::{Deponency, MovableNu};
+
+// Each table gives the masculine, feminine and neuter endings, singular then
+// plural, in the order nom, voc, acc, gen, dat.
+pub type Table = [[&'static str; 5]; 6];
+
+// -ων, -ουσα, -ον: present, future and strong aorist actives
+pub const THEMATIC: Table = [
+    ["ων", "ων", "οντα", "οντος", "οντι"],
+    ["οντες", "οντες", "οντας", "οντων", "ουσι"],
+    ["ουσα", "ουσα", "ουσαν", "ουσης", "ουσῃ"],
+    ["ουσαι", "ουσαι", "ουσας", "ουσων", "ουσαις"],
+    ["ον", "ον", "ον", "οντος", "οντι"],
+    ["οντα", "οντα", "οντα", "οντων", "ουσι"],
+];
+
+// -ας, -ασα, -αν: weak aorist actives
+pub const WEAK_AORIST: Table = [
+    ["ας", "ας", "αντα", "αντος", "αντι"],
+    ["αντες", "αντες", "αντας", "αντων", "ασι"],
+    ["ασα", "ασα", "ασαν", "ασης", "ασῃ"],
+    ["ασαι", "ασαι", "ασας", "ασων", "ασαις"],
+    ["αν", "αν", "αν", "αντος", "αντι"],
+    ["αντα", "αντα", "αντα", "αντων", "ασι"],
+];
+
+// -εις, -εισα, -εν: aorist passives
+pub const AORIST_PASSIVE: Table = [
+    ["εις", "εις", "εντα", "εντος", "εντι"],
+    ["εντες", "εντες", "εντας", "εντων", "εισι"],
+    ["εισα", "εισα", "εισαν", "εισης", "εισῃ"],
+    ["εισαι", "εισαι", "εισας", "εισων", "εισαις"],
+    ["εν", "εν", "εν", "εντος", "εντι"],
+    ["εντα", "εντα", "εντα", "εντων", "εισι"],
+];
+
+// -ους, -ουσα, -ον: διδους and root aorists in -ω-
+pub const OMICRON_ROOT: Table = [
+    ["ους", "ους", "οντα", "οντος", "οντι"],
+    ["οντες", "οντες", "οντας", "οντων", "ουσι"],
+    ["ουσα", "ουσα", "ουσαν", "ουσης", "ουσῃ"],
+    ["ουσαι", "ουσαι", "ουσας", "ουσων", "ουσαις"],
+    ["ον", "ον", "ον", "οντος", "οντι"],
+    ["οντα", "οντα", "οντα", "οντων", "ουσι"],
+];
+
+// -υς, -υσα, -υν: δεικνυς
+pub const UPSILON_ROOT: Table = [
+    ["υς", "υς", "υντα", "υντος", "υντι"],
+    ["υντες", "υντες", "υντας", "υντων", "υσι"],
+    ["υσα", "υσα", "υσαν", "υσης", "υσῃ"],
+    ["υσαι", "υσαι", "υσας", "υσων", "υσαις"],
+    ["υν", "υν", "υν", "υντος", "υντι"],
+    ["υντα", "υντα", "υντα", "υντων", "υσι"],
+];
+
+// -ως, -υια, -ος: perfect actives such as εἰδως
+pub const PERFECT: Table = [
+    ["ως", "ως", "οτα", "οτος", "οτι"],
+    ["οτες", "οτες", "οτας", "οτων", "οσι"],
+    ["υια", "υια", "υιαν", "υιας", "υιᾳ"],
+    ["υιαι", "υιαι", "υιας", "υιων", "υιαις"],
+    ["ος", "ος", "ος", "οτος", "οτι"],
+    ["οτα", "οτα", "οτα", "οτων", "οσι"],
+];
+
+// ὠν, οὐσα, ὀν: εἰμι, whose participle is all ending
+pub const EIMI: Table = [
+    ["ὠν", "ὠν", "ὀντα", "ὀντος", "ὀντι"],
+    ["ὀντες", "ὀντες", "ὀντας", "ὀντων", "οὐσι"],
+    ["οὐσα", "οὐσα", "οὐσαν", "οὐσης", "οὐσῃ"],
+    ["οὐσαι", "οὐσαι", "οὐσας", "οὐσων", "οὐσαις"],
+    ["ὀν", "ὀν", "ὀν", "ὀντος", "ὀντι"],
+    ["ὀντα", "ὀντα", "ὀντα", "ὀντων", "οὐσι"],
+];
+
+// -μενος, -μενη, -μενον: all middles and passives other than the aorist
+// passive, and the verbal adjectives in -τος
+pub const MIDDLE: Table = [
+    ["ος", "ε", "ον", "ου", "ῳ"],
+    ["οι", "οι", "ους", "ων", "οις"],
+    ["η", "η", "ην", "ης", "ῃ"],
+    ["αι", "αι", "ας", "ων", "αις"],
+    ["ον", "ον", "ον", "ου", "ῳ"],
+    ["α", "α", "α", "ων", "οις"],
+];
+
+// -ος, -α, -ον: adjectives with ε, ι or ρ before the ending, as the verbal
+// adjectives in -τεος
+pub const ALPHA_PURE: Table = [
+    ["ος", "ε", "ον", "ου", "ῳ"],
+    ["οι", "οι", "ους", "ων", "οις"],
+    ["α", "α", "αν", "ας", "ᾳ"],
+    ["αι", "αι", "ας", "ων", "αις"],
+    ["ον", "ον", "ον", "ου", "ῳ"],
+    ["α", "α", "α", "ων", "οις"],
+];
+
+#[derive(Debug)]
+pub enum Declined {
+    // masculine, feminine, neuter; singular then plural
+    Some(Vec<Vec<String>>),
+    None,
+}
+
+impl Declined {
+    pub fn print(&self, note: Deponency) {
+        match self {
+            Declined::Some(rows) => {
+                for row in rows {
+                    match note {
+                        Deponency::None => println!("{}", row.join(", ")),
+                        _ => println!("{} ({})", row.join(", "), note),
+                    }
+                }
+            }
+            Declined::None => {}
+        }
+    }
+
+    // only the dative plurals end in -σι
+    pub fn movable_nu(&mut self, nu: MovableNu) {
+        if let Declined::Some(rows) = self {
+            for part in rows.iter_mut().flatten() {
+                if unaccented(part).ends_with("σι") {
+                    nu.apply(part);
+                }
+            }
+        }
+    }
+
+    // Every form keeps the accent of the masculine nominative singular as far
+    // as its last syllable allows. The feminine genitive plural of a
+    // participle is always perispomenon, as the first declension's is, and so
+    // are the genitives and datives of an oxytone adjective: λυθεισῶν, λυτοῦ.
+    pub fn accent(&mut self, nominative: Nominative) {
+        if let Declined::Some(rows) = self {
+            let nom = match rows.first().and_then(|row| row.first()) {
+                Some(nom) => nom.clone(),
+                None => return,
+            };
+            let plain = unaccented(&nom);
+            let lengthened = plain.ends_with("ας") || plain.ends_with("υς");
+            let at = accent_index(&nom).unwrap_or_else(|| match nominative {
+                Nominative::Recessive(floor) => recessive_index(&nom, floor, lengthened),
+                Nominative::Penult => syllable_count(&nom).saturating_sub(2),
+                Nominative::Oxytone => syllable_count(&nom).saturating_sub(1),
+            });
+            let ending = if lengthened {
+                Some(syllable_count(&nom) - 1)
+            } else {
+                None
+            };
+            let adjective = plain.ends_with("ος");
+            for (row, forms) in rows.iter_mut().enumerate() {
+                let feminine = row == 2 || row == 3;
+                for (case, part) in forms.iter_mut().enumerate() {
+                    *part = persistent(part, at, ending, feminine);
+                    let oblique = case >= 3 && syllable_count(part) == at + 1;
+                    if adjective && nominative == Nominative::Oxytone && oblique {
+                        *part = perispomenon(part);
+                    }
+                }
+            }
+            if !adjective {
+                if let Some(part) = rows.get_mut(3).and_then(|row| row.get_mut(3)) {
+                    *part = perispomenon(part);
+                }
+            }
+        }
+    }
+
+    // Swaps an ending wherever it appears: Ionic λυομενοισι for λυομενοις
+    pub fn replace_endings(&mut self, from: &str, to: &str) {
+        if let Declined::Some(rows) = self {
+            for part in rows.iter_mut().flatten() {
+                if let Some(stem) = part.strip_suffix(from) {
+                    *part = format!("{}{}", stem, to);
+                }
+            }
+        }
+    }
+
+    pub fn to_vecs(&self) -> Option<&Vec<Vec<String>>> {
+        match self {
+            Declined::Some(rows) => Some(rows),
+            _ => None,
+        }
+    }
+}
+
+pub fn decline(stem: &str, table: &Table) -> Declined {
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    for endings in table.iter() {
+        let mut forms: Vec<String> = Vec::new();
+        for ending in endings.iter() {
+            let part = format!("{}{}", stem, ending);
+            forms.push(part);
+        }
+        rows.push(forms);
+    }
+    Declined::Some(rows)
+}
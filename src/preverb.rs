@@ -0,0 +1,383 @@
+// Prepositional prefixes of compound verbs. A preverb changes shape with
+// what follows it: it elides before a vowel (ἀπ-, δι-), aspirates before a
+// rough breathing (ἀφ-, καθ-), and ἐν and συν assimilate to a following
+// consonant (ἐμ-, συλ-). A compound is recognised by trying each shape and
+// checking the verb can be put back together from it.
+struct Preverb {
+    // before a consonant
+    full: &'static str,
+    // before a vowel with smooth breathing
+    smooth: &'static str,
+    // before a vowel with rough breathing
+    rough: &'static str,
+}
+
+// ὑπερ before ὑπο and προσ before προ, so the longer one is tried first
+const PREVERBS: [Preverb; 18] = [
+    Preverb {
+        full: "ἀμφι",
+        smooth: "ἀμφ",
+        rough: "ἀμφ",
+    },
+    Preverb {
+        full: "ἀνα",
+        smooth: "ἀν",
+        rough: "ἀν",
+    },
+    Preverb {
+        full: "ἀντι",
+        smooth: "ἀντ",
+        rough: "ἀνθ",
+    },
+    Preverb {
+        full: "ἀπο",
+        smooth: "ἀπ",
+        rough: "ἀφ",
+    },
+    Preverb {
+        full: "δια",
+        smooth: "δι",
+        rough: "δι",
+    },
+    Preverb {
+        full: "εἰσ",
+        smooth: "εἰσ",
+        rough: "εἰσ",
+    },
+    Preverb {
+        full: "ἐκ",
+        smooth: "ἐξ",
+        rough: "ἐξ",
+    },
+    Preverb {
+        full: "ἐν",
+        smooth: "ἐν",
+        rough: "ἐν",
+    },
+    Preverb {
+        full: "ἐπι",
+        smooth: "ἐπ",
+        rough: "ἐφ",
+    },
+    Preverb {
+        full: "κατα",
+        smooth: "κατ",
+        rough: "καθ",
+    },
+    Preverb {
+        full: "μετα",
+        smooth: "μετ",
+        rough: "μεθ",
+    },
+    Preverb {
+        full: "παρα",
+        smooth: "παρ",
+        rough: "παρ",
+    },
+    Preverb {
+        full: "περι",
+        smooth: "περι",
+        rough: "περι",
+    },
+    Preverb {
+        full: "προσ",
+        smooth: "προσ",
+        rough: "προσ",
+    },
+    Preverb {
+        full: "προ",
+        smooth: "προ",
+        rough: "προ",
+    },
+    Preverb {
+        full: "συν",
+        smooth: "συν",
+        rough: "συν",
+    },
+    Preverb {
+        full: "ὑπερ",
+        smooth: "ὑπερ",
+        rough: "ὑπερ",
+    },
+    Preverb {
+        full: "ὑπο",
+        smooth: "ὑπ",
+        rough: "ὑφ",
+    },
+];
+
+// Compounds no longer felt as such, which take the augment in front of the
+// preverb: ἐκαθευδον, ἐκαθιζον, ἐκαθημην
+const LEXICALISED: [&str; 4] = ["καθευδ", "καθιζ", "καθισ", "καθημ"];
+
+// bare vowel, smooth, rough
+const BREATHINGS: [(char, char, char); 10] = [
+    ('α', 'ἀ', 'ἁ'),
+    ('ε', 'ἐ', 'ἑ'),
+    ('η', 'ἠ', 'ἡ'),
+    ('ι', 'ἰ', 'ἱ'),
+    ('ο', 'ὀ', 'ὁ'),
+    ('υ', 'ὐ', 'ὑ'),
+    ('ω', 'ὠ', 'ὡ'),
+    ('ᾳ', 'ᾀ', 'ᾁ'),
+    ('ῃ', 'ᾐ', 'ᾑ'),
+    ('ῳ', 'ᾠ', 'ᾡ'),
+];
+
+pub fn bare(c: char) -> char {
+    BREATHINGS
+        .iter()
+        .find(|(_, smooth, rough)| c == *smooth || c == *rough)
+        .map_or(c, |(vowel, _, _)| *vowel)
+}
+
+pub fn is_vowel(c: char) -> bool {
+    BREATHINGS.iter().any(|(vowel, _, _)| bare(c) == *vowel)
+}
+
+// The breathing sits on the second letter of a diphthong: αἰ, εὐ, οἱ
+fn breathing_at(chars: &[char]) -> usize {
+    if chars.len() > 1
+        && matches!(bare(chars[0]), 'α' | 'ε' | 'ο' | 'η')
+        && matches!(bare(chars[1]), 'ι' | 'υ')
+    {
+        1
+    } else {
+        0
+    }
+}
+
+pub fn is_rough(part: &str) -> bool {
+    let chars: Vec<char> = part.chars().collect();
+    match chars.get(breathing_at(&chars)) {
+        Some(c) => BREATHINGS.iter().any(|(_, _, rough)| c == rough),
+        None => false,
+    }
+}
+
+pub fn strip_breathing(part: &str) -> String {
+    let mut chars: Vec<char> = part.chars().collect();
+    let at = breathing_at(&chars);
+    if let Some(c) = chars.get_mut(at) {
+        *c = bare(*c);
+    }
+    chars.into_iter().collect()
+}
+
+// An initial υ is always rough
+pub fn breathe(part: &str, rough: bool) -> String {
+    let mut chars: Vec<char> = part.chars().collect();
+    let at = breathing_at(&chars);
+    if let Some(c) = chars.get_mut(at) {
+        if let Some((_, smooth, rgh)) = BREATHINGS.iter().find(|(vowel, _, _)| *vowel == *c) {
+            *c = if rough || (at == 0 && *c == 'υ') {
+                *rgh
+            } else {
+                *smooth
+            };
+        }
+    }
+    chars.into_iter().collect()
+}
+
+impl Preverb {
+    // συμβαλλω, συγχεω, συλλεγω, συρρεω, συστελλω; ἐμβαλλω, ἐγχεω
+    fn assimilate(&self, next: char) -> String {
+        if self.full != "ἐν" && self.full != "συν" {
+            return self.full.to_string();
+        }
+        let stem = self.full.trim_end_matches('ν');
+        match next {
+            'π' | 'β' | 'φ' | 'ψ' | 'μ' => format!("{}μ", stem),
+            'γ' | 'κ' | 'χ' | 'ξ' => format!("{}γ", stem),
+            'λ' => format!("{}λ", stem),
+            'ρ' => format!("{}ρ", stem),
+            'σ' | 'ζ' if self.full == "συν" => stem.to_string(),
+            _ => self.full.to_string(),
+        }
+    }
+
+    // The full form comes first, so ἀποθνῃσκω is ἀπο-θνῃσκω and not ἀπ-οθνῃσκω
+    fn shapes(&self) -> Vec<String> {
+        let mut shapes = vec![
+            self.full.to_string(),
+            self.smooth.to_string(),
+            self.rough.to_string(),
+        ];
+        for next in ['μ', 'γ', 'λ', 'ρ', 'σ'].iter() {
+            shapes.push(self.assimilate(*next));
+        }
+        let mut seen: Vec<String> = Vec::new();
+        shapes.retain(|shape| {
+            let first = !seen.contains(shape);
+            seen.push(shape.clone());
+            first
+        });
+        shapes
+    }
+
+    fn join(&self, simplex: &str) -> String {
+        match simplex.chars().next() {
+            Some(c) if is_vowel(c) => {
+                let unbreathed = strip_breathing(simplex);
+                // προ and the augment run together as πρου-
+                if self.full == "προ" && unbreathed.starts_with('ε') {
+                    format!("πρου{}", &unbreathed['ε'.len_utf8()..])
+                } else if is_rough(simplex) {
+                    format!("{}{}", self.rough, unbreathed)
+                } else {
+                    format!("{}{}", self.smooth, unbreathed)
+                }
+            }
+            // ῥ loses its breathing inside a word, and doubles after a vowel
+            Some('ῥ') => {
+                let preverb = self.assimilate('ρ');
+                let rest = &simplex['ῥ'.len_utf8()..];
+                if preverb.ends_with(is_vowel) {
+                    format!("{}ρρ{}", preverb, rest)
+                } else {
+                    format!("{}ρ{}", preverb, rest)
+                }
+            }
+            Some(c) => format!("{}{}", self.assimilate(c), simplex),
+            None => self.full.to_string(),
+        }
+    }
+
+    // The simple verb left once the preverb is taken off, and whether the
+    // preverb had changed its shape to fit
+    fn strip(&self, stem: &str) -> Option<(String, bool)> {
+        for shape in self.shapes().iter() {
+            let rest = match stem.strip_prefix(shape.as_str()) {
+                Some(rest) if rest.chars().count() > 1 => rest,
+                _ => continue,
+            };
+            let simplex = match rest.chars().next() {
+                // πρου- is προ with the augment
+                Some('υ') if self.full == "προ" => format!("ἐ{}", &rest['υ'.len_utf8()..]),
+                Some(c) if is_vowel(c) => {
+                    breathe(rest, shape == self.rough && self.rough != self.smooth)
+                }
+                Some('ρ') => format!("ῥ{}", rest.trim_start_matches('ρ')),
+                _ => rest.to_string(),
+            };
+            if self.join(&simplex) == stem {
+                return Some((simplex, shape != self.full));
+            }
+        }
+        None
+    }
+}
+
+pub struct Compound {
+    // outermost first: συν, ἐκ in συνεκπεμπω
+    preverbs: Vec<&'static Preverb>,
+    // the simple verb with its initial breathing put back
+    pub simplex: String,
+}
+
+impl Compound {
+    // Puts the preverbs back on a form of the simple verb, innermost first
+    pub fn rejoin(&self, simplex: &str) -> String {
+        self.preverbs
+            .iter()
+            .rev()
+            .fold(simplex.to_string(), |part, preverb| preverb.join(&part))
+    }
+}
+
+// A preverb is only taken off when another principal part has it too, so
+// that ἐπεμψα and ἐδιωξα stay whole. Without one to check against, only a
+// preverb in its full form is trusted. What is left is split again, for
+// verbs with more than one preverb.
+pub fn split(stem: &str, other: Option<&str>) -> Option<Compound> {
+    let unaugmented = stem.strip_prefix('ἐ').unwrap_or(stem);
+    if LEXICALISED.iter().any(|cpd| unaugmented.starts_with(cpd)) {
+        return None;
+    }
+    for preverb in PREVERBS.iter() {
+        let (simplex, elided) = match preverb.strip(stem) {
+            Some(found) => found,
+            None => continue,
+        };
+        let other_simplex = match other {
+            Some(part) => match preverb.strip(part) {
+                Some((simplex, _)) => Some(simplex),
+                None => continue,
+            },
+            None if elided => continue,
+            None => None,
+        };
+        let mut compound = Compound {
+            preverbs: vec![preverb],
+            simplex,
+        };
+        if let Some(inner) = split(&compound.simplex, other_simplex.as_deref()) {
+            compound.preverbs.extend(inner.preverbs);
+            compound.simplex = inner.simplex;
+        }
+        return Some(compound);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // stem, other principal part, simple verb, a simple form and the
+    // compound it makes
+    #[test]
+    fn compounds() {
+        let cases = [
+            ("ἀφικν", Some("ἀφιξ"), "ἱκν", "ἱκομ", "ἀφικομ"),
+            ("συλλεγ", Some("συνελεξ"), "λεγ", "ἐλεξ", "συνελεξ"),
+            ("ἐκβαλλ", Some("ἐξεβαλ"), "βαλλ", "ἐβαλ", "ἐξεβαλ"),
+            ("προπεμπ", Some("προυπεμψ"), "πεμπ", "ἐπεμψ", "προυπεμψ"),
+            ("ἀποθνῃσκ", None, "θνῃσκ", "ἐθαν", "ἀπεθαν"),
+            ("διαρρηγνυ", Some("διερρηξ"), "ῥηγνυ", "ἐρρηξ", "διερρηξ"),
+            ("ἀναρριπτ", Some("ἀνερριψ"), "ῥιπτ", "ἐρριψ", "ἀνερριψ"),
+            ("συρρε", Some("συνερρυ"), "ῥε", "ἐρρυ", "συνερρυ"),
+            ("συνεκπεμπ", None, "πεμπ", "ἐπεμψ", "συνεξεπεμψ"),
+            (
+                "συνεκπεμπ",
+                Some("συνεξεπεμψ"),
+                "πεμπ",
+                "ἐπεμπ",
+                "συνεξεπεμπ",
+            ),
+            ("ἀντεπεξαγ", Some("ἀντεπεξηγ"), "ἀγ", "ἠγ", "ἀντεπεξηγ"),
+        ];
+        for (stem, other, simplex, form, compound) in cases.iter() {
+            let split = split(stem, *other).unwrap_or_else(|| panic!("{} not split", stem));
+            assert_eq!(split.simplex, *simplex);
+            assert_eq!(split.rejoin(form), *compound);
+        }
+    }
+
+    // The augment goes before a lexicalised compound: ἐκαθιζον
+    #[test]
+    fn lexicalised() {
+        assert!(split("καθιζ", Some("ἐκαθισ")).is_none());
+        assert!(split("ἐκαθευδ", Some("καθευδ")).is_none());
+        assert!(split("καθημ", None).is_none());
+    }
+
+    // ἀναγκαζω looks like ἀν-αγκαζω, but ἠναγκαζον has no ἀν- to match
+    #[test]
+    fn false_positives() {
+        assert!(split("ἀναγκαζ", Some("ἠναγκαζ")).is_none());
+        assert!(split("ἐπεμψ", None).is_none());
+        assert!(split("ἐδιωξ", None).is_none());
+    }
+
+    #[test]
+    fn breathings() {
+        assert!(is_rough("ἱκν"));
+        assert!(is_rough("αἱρ"));
+        assert!(!is_rough("εἰπ"));
+        assert_eq!(breathe("υπνο", false), "ὑπνο");
+        assert_eq!(breathe("αιρ", true), "αἱρ");
+        assert_eq!(strip_breathing("οἰκ"), "οικ");
+    }
+}
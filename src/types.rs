@@ -1,3 +1,4 @@
+use crate::accent::{recessive, unaccented};
 use std::fmt;
 
 pub enum TenseVoiceMoodVariant {
@@ -9,16 +10,32 @@ pub enum TenseVoiceMoodVariant {
     PresPassIndAlp(String),
     PresPassIndEps(String),
     PresPassIndOmi(String),
+    PresActIndMiOmi(String),
+    PresActIndMiEps(String),
+    PresActIndMiAlp(String),
+    PresActIndMiUps(String),
+    PresIndIrr(String),
     FutActIndReg(String),
     FutActIndEps(String),
     FutMidIndReg(String),
     FutMidIndEps(String),
     FutPassIndReg(String),
+    FutIndIrr(String),
     AorActIndWk(String),
     AorActIndStr(String),
+    AorActIndKap(String),
+    AorActIndRoot(String),
     AorMidIndWk(String),
     AorMidIndStr(String),
     AorPassIndWk(String),
+    AorPassIndStr(String),
+    PerfActIndKap(String),
+    PerfActIndStr(String),
+    PerfMidIndVow(String),
+    PerfMidIndLab(String),
+    PerfMidIndVel(String),
+    PerfMidIndDen(String),
+    PerfMidIndLiq(String),
 }
 
 impl fmt::Display for TenseVoiceMoodVariant {
@@ -32,16 +49,32 @@ impl fmt::Display for TenseVoiceMoodVariant {
             TenseVoiceMoodVariant::PresPassIndAlp(val) => write!(f, "{}", val),
             TenseVoiceMoodVariant::PresPassIndEps(val) => write!(f, "{}", val),
             TenseVoiceMoodVariant::PresPassIndOmi(val) => write!(f, "{}", val),
+            TenseVoiceMoodVariant::PresActIndMiOmi(val) => write!(f, "{}", val),
+            TenseVoiceMoodVariant::PresActIndMiEps(val) => write!(f, "{}", val),
+            TenseVoiceMoodVariant::PresActIndMiAlp(val) => write!(f, "{}", val),
+            TenseVoiceMoodVariant::PresActIndMiUps(val) => write!(f, "{}", val),
+            TenseVoiceMoodVariant::PresIndIrr(val) => write!(f, "{}", val),
             TenseVoiceMoodVariant::FutActIndReg(val) => write!(f, "{}", val),
             TenseVoiceMoodVariant::FutActIndEps(val) => write!(f, "{}", val),
             TenseVoiceMoodVariant::FutMidIndReg(val) => write!(f, "{}", val),
             TenseVoiceMoodVariant::FutMidIndEps(val) => write!(f, "{}", val),
             TenseVoiceMoodVariant::FutPassIndReg(val) => write!(f, "{}", val),
+            TenseVoiceMoodVariant::FutIndIrr(val) => write!(f, "{}", val),
             TenseVoiceMoodVariant::AorActIndWk(val) => write!(f, "{}", val),
             TenseVoiceMoodVariant::AorActIndStr(val) => write!(f, "{}", val),
+            TenseVoiceMoodVariant::AorActIndKap(val) => write!(f, "{}", val),
+            TenseVoiceMoodVariant::AorActIndRoot(val) => write!(f, "{}", val),
             TenseVoiceMoodVariant::AorMidIndWk(val) => write!(f, "{}", val),
             TenseVoiceMoodVariant::AorMidIndStr(val) => write!(f, "{}", val),
             TenseVoiceMoodVariant::AorPassIndWk(val) => write!(f, "{}", val),
+            TenseVoiceMoodVariant::AorPassIndStr(val) => write!(f, "{}", val),
+            TenseVoiceMoodVariant::PerfActIndKap(val) => write!(f, "{}", val),
+            TenseVoiceMoodVariant::PerfActIndStr(val) => write!(f, "{}", val),
+            TenseVoiceMoodVariant::PerfMidIndVow(val) => write!(f, "{}", val),
+            TenseVoiceMoodVariant::PerfMidIndLab(val) => write!(f, "{}", val),
+            TenseVoiceMoodVariant::PerfMidIndVel(val) => write!(f, "{}", val),
+            TenseVoiceMoodVariant::PerfMidIndDen(val) => write!(f, "{}", val),
+            TenseVoiceMoodVariant::PerfMidIndLiq(val) => write!(f, "{}", val),
         }
     }
 }
@@ -57,59 +90,127 @@ impl fmt::Debug for TenseVoiceMoodVariant {
             TenseVoiceMoodVariant::PresPassIndAlp(val) => write!(f, "PresPassIndAlp: \"{}\"", val),
             TenseVoiceMoodVariant::PresPassIndEps(val) => write!(f, "PresPassIndEps: \"{}\"", val),
             TenseVoiceMoodVariant::PresPassIndOmi(val) => write!(f, "PresPassIndOmi: \"{}\"", val),
+            TenseVoiceMoodVariant::PresActIndMiOmi(val) => {
+                write!(f, "PresActIndMiOmi: \"{}\"", val)
+            }
+            TenseVoiceMoodVariant::PresActIndMiEps(val) => {
+                write!(f, "PresActIndMiEps: \"{}\"", val)
+            }
+            TenseVoiceMoodVariant::PresActIndMiAlp(val) => {
+                write!(f, "PresActIndMiAlp: \"{}\"", val)
+            }
+            TenseVoiceMoodVariant::PresActIndMiUps(val) => {
+                write!(f, "PresActIndMiUps: \"{}\"", val)
+            }
+            TenseVoiceMoodVariant::PresIndIrr(val) => write!(f, "PresIndIrr: \"{}\"", val),
             TenseVoiceMoodVariant::FutActIndReg(val) => write!(f, "FutActIndReg: \"{}\"", val),
             TenseVoiceMoodVariant::FutActIndEps(val) => write!(f, "FutActIndEps: \"{}\"", val),
             TenseVoiceMoodVariant::FutMidIndReg(val) => write!(f, "FutMidIndReg: \"{}\"", val),
             TenseVoiceMoodVariant::FutMidIndEps(val) => write!(f, "FutMidIndEps: \"{}\"", val),
             TenseVoiceMoodVariant::FutPassIndReg(val) => write!(f, "FutPassIndReg: \"{}\"", val),
+            TenseVoiceMoodVariant::FutIndIrr(val) => write!(f, "FutIndIrr: \"{}\"", val),
             TenseVoiceMoodVariant::AorActIndWk(val) => write!(f, "AorActIndWk: \"{}\"", val),
             TenseVoiceMoodVariant::AorActIndStr(val) => write!(f, "AorActIndStr: \"{}\"", val),
+            TenseVoiceMoodVariant::AorActIndKap(val) => write!(f, "AorActIndKap: \"{}\"", val),
+            TenseVoiceMoodVariant::AorActIndRoot(val) => write!(f, "AorActIndRoot: \"{}\"", val),
             TenseVoiceMoodVariant::AorMidIndWk(val) => write!(f, "AorMidIndWk: \"{}\"", val),
             TenseVoiceMoodVariant::AorMidIndStr(val) => write!(f, "AorMidIndStr: \"{}\"", val),
             TenseVoiceMoodVariant::AorPassIndWk(val) => write!(f, "AorPassIndWk: \"{}\"", val),
+            TenseVoiceMoodVariant::AorPassIndStr(val) => write!(f, "AorPassIndStr: \"{}\"", val),
+            TenseVoiceMoodVariant::PerfActIndKap(val) => write!(f, "PerfActIndKap: \"{}\"", val),
+            TenseVoiceMoodVariant::PerfActIndStr(val) => write!(f, "PerfActIndStr: \"{}\"", val),
+            TenseVoiceMoodVariant::PerfMidIndVow(val) => write!(f, "PerfMidIndVow: \"{}\"", val),
+            TenseVoiceMoodVariant::PerfMidIndLab(val) => write!(f, "PerfMidIndLab: \"{}\"", val),
+            TenseVoiceMoodVariant::PerfMidIndVel(val) => write!(f, "PerfMidIndVel: \"{}\"", val),
+            TenseVoiceMoodVariant::PerfMidIndDen(val) => write!(f, "PerfMidIndDen: \"{}\"", val),
+            TenseVoiceMoodVariant::PerfMidIndLiq(val) => write!(f, "PerfMidIndLiq: \"{}\"", val),
         }
     }
 }
 
 impl TenseVoiceMoodVariant {
     pub fn first_char(&self) -> String {
-        let first: String = match &self {
-            &TenseVoiceMoodVariant::PresActIndReg(val) => {
+        let first: String = match self {
+            TenseVoiceMoodVariant::PresActIndReg(val) => {
                 format!("{}", val.chars().next().unwrap())
             }
-            &TenseVoiceMoodVariant::PresActIndAlp(val) => {
+            TenseVoiceMoodVariant::PresActIndAlp(val) => {
                 format!("{}", val.chars().next().unwrap())
             }
-            &TenseVoiceMoodVariant::PresActIndEps(val) => {
+            TenseVoiceMoodVariant::PresActIndEps(val) => {
                 format!("{}", val.chars().next().unwrap())
             }
-            &TenseVoiceMoodVariant::PresActIndOmi(val) => {
+            TenseVoiceMoodVariant::PresActIndOmi(val) => {
                 format!("{}", val.chars().next().unwrap())
             }
-            &TenseVoiceMoodVariant::PresPassIndReg(val) => {
+            TenseVoiceMoodVariant::PresPassIndReg(val) => {
                 format!("{}", val.chars().next().unwrap())
             }
-            &TenseVoiceMoodVariant::PresPassIndAlp(val) => {
+            TenseVoiceMoodVariant::PresPassIndAlp(val) => {
                 format!("{}", val.chars().next().unwrap())
             }
-            &TenseVoiceMoodVariant::PresPassIndEps(val) => {
+            TenseVoiceMoodVariant::PresPassIndEps(val) => {
                 format!("{}", val.chars().next().unwrap())
             }
-            &TenseVoiceMoodVariant::PresPassIndOmi(val) => {
+            TenseVoiceMoodVariant::PresPassIndOmi(val) => {
                 format!("{}", val.chars().next().unwrap())
             }
-            &TenseVoiceMoodVariant::FutActIndReg(val) => format!("{}", val.chars().next().unwrap()),
-            &TenseVoiceMoodVariant::FutActIndEps(val) => format!("{}", val.chars().next().unwrap()),
-            &TenseVoiceMoodVariant::FutMidIndReg(val) => format!("{}", val.chars().next().unwrap()),
-            &TenseVoiceMoodVariant::FutMidIndEps(val) => format!("{}", val.chars().next().unwrap()),
-            &TenseVoiceMoodVariant::FutPassIndReg(val) => {
+            TenseVoiceMoodVariant::PresActIndMiOmi(val) => {
+                format!("{}", val.chars().next().unwrap())
+            }
+            TenseVoiceMoodVariant::PresActIndMiEps(val) => {
+                format!("{}", val.chars().next().unwrap())
+            }
+            TenseVoiceMoodVariant::PresActIndMiAlp(val) => {
+                format!("{}", val.chars().next().unwrap())
+            }
+            TenseVoiceMoodVariant::PresActIndMiUps(val) => {
+                format!("{}", val.chars().next().unwrap())
+            }
+            TenseVoiceMoodVariant::PresIndIrr(val) => format!("{}", val.chars().next().unwrap()),
+            TenseVoiceMoodVariant::FutActIndReg(val) => format!("{}", val.chars().next().unwrap()),
+            TenseVoiceMoodVariant::FutActIndEps(val) => format!("{}", val.chars().next().unwrap()),
+            TenseVoiceMoodVariant::FutMidIndReg(val) => format!("{}", val.chars().next().unwrap()),
+            TenseVoiceMoodVariant::FutMidIndEps(val) => format!("{}", val.chars().next().unwrap()),
+            TenseVoiceMoodVariant::FutPassIndReg(val) => {
+                format!("{}", val.chars().next().unwrap())
+            }
+            TenseVoiceMoodVariant::FutIndIrr(val) => format!("{}", val.chars().next().unwrap()),
+            TenseVoiceMoodVariant::AorActIndWk(val) => format!("{}", val.chars().next().unwrap()),
+            TenseVoiceMoodVariant::AorActIndStr(val) => format!("{}", val.chars().next().unwrap()),
+            TenseVoiceMoodVariant::AorActIndKap(val) => {
+                format!("{}", val.chars().next().unwrap())
+            }
+            TenseVoiceMoodVariant::AorActIndRoot(val) => {
+                format!("{}", val.chars().next().unwrap())
+            }
+            TenseVoiceMoodVariant::AorMidIndWk(val) => format!("{}", val.chars().next().unwrap()),
+            TenseVoiceMoodVariant::AorMidIndStr(val) => format!("{}", val.chars().next().unwrap()),
+            TenseVoiceMoodVariant::AorPassIndWk(val) => format!("{}", val.chars().next().unwrap()),
+            TenseVoiceMoodVariant::AorPassIndStr(val) => {
+                format!("{}", val.chars().next().unwrap())
+            }
+            TenseVoiceMoodVariant::PerfActIndKap(val) => {
+                format!("{}", val.chars().next().unwrap())
+            }
+            TenseVoiceMoodVariant::PerfActIndStr(val) => {
+                format!("{}", val.chars().next().unwrap())
+            }
+            TenseVoiceMoodVariant::PerfMidIndVow(val) => {
+                format!("{}", val.chars().next().unwrap())
+            }
+            TenseVoiceMoodVariant::PerfMidIndLab(val) => {
+                format!("{}", val.chars().next().unwrap())
+            }
+            TenseVoiceMoodVariant::PerfMidIndVel(val) => {
+                format!("{}", val.chars().next().unwrap())
+            }
+            TenseVoiceMoodVariant::PerfMidIndDen(val) => {
+                format!("{}", val.chars().next().unwrap())
+            }
+            TenseVoiceMoodVariant::PerfMidIndLiq(val) => {
                 format!("{}", val.chars().next().unwrap())
             }
-            &TenseVoiceMoodVariant::AorActIndWk(val) => format!("{}", val.chars().next().unwrap()),
-            &TenseVoiceMoodVariant::AorActIndStr(val) => format!("{}", val.chars().next().unwrap()),
-            &TenseVoiceMoodVariant::AorMidIndWk(val) => format!("{}", val.chars().next().unwrap()),
-            &TenseVoiceMoodVariant::AorMidIndStr(val) => format!("{}", val.chars().next().unwrap()),
-            &TenseVoiceMoodVariant::AorPassIndWk(val) => format!("{}", val.chars().next().unwrap()),
         };
         first
     }
@@ -121,24 +222,109 @@ pub enum Conjugated {
     None,
 }
 
+// Forms run 1s, 2s, 3s, 1p, 2p, 3p, followed by the 2nd and 3rd person
+// duals, which are only given out when asked for.
 impl Conjugated {
-    pub fn print(&self) {
-        match self {
-            Conjugated::Some(v) => {
-                let mut s = String::new();
-                for part in v {
-                    s.push_str(format!(", {}", part).as_ref());
+    pub fn print(&self, dual: bool, note: Deponency) {
+        if let Some(v) = self.to_vec(dual) {
+            let mut s = String::new();
+            for part in v.iter().filter(|part| !part.is_empty()) {
+                s.push_str(format!(", {}", part).as_ref());
+            }
+            match note {
+                Deponency::None => println!("{}", &s[2..]),
+                _ => println!("{} ({})", &s[2..], note),
+            }
+        }
+    }
+
+    // ἐλυσε(ν), λυουσι(ν), ἐστι(ν); -ει only takes it in the pluperfect,
+    // so ἐλελυκει(ν) but ἐποιει
+    pub fn movable_nu(&mut self, nu: MovableNu, pluperfect: bool) {
+        if let Conjugated::Some(forms) = self {
+            for (i, part) in forms.iter_mut().enumerate() {
+                let plain = unaccented(part);
+                let third_sg =
+                    i == 2 && (plain.ends_with('ε') || (pluperfect && plain.ends_with("ει")));
+                if third_sg || plain.ends_with("σι") {
+                    nu.apply(part);
+                }
+            }
+        }
+    }
+
+    pub fn accent(&mut self, optative: bool, floor: usize) {
+        if let Conjugated::Some(forms) = self {
+            for part in forms.iter_mut() {
+                *part = recessive(part, optative, floor);
+            }
+        }
+    }
+
+    // Swaps one form's ending for another: Ionic λυεαι for λυῃ
+    pub fn replace_ending(&mut self, index: usize, from: &str, to: &str) {
+        if let Conjugated::Some(forms) = self {
+            if let Some(part) = forms.get_mut(index) {
+                if let Some(stem) = part.strip_suffix(from) {
+                    *part = format!("{}{}", stem, to);
                 }
-                println!("{}", &s[2..]);
             }
-            Conjugated::None => {}
         }
     }
 
-    pub fn to_vec(&self) -> Option<&Vec<String>> {
+    pub fn to_vec(&self, dual: bool) -> Option<&[String]> {
         match self {
-            Conjugated::Some(v) => Some(&v),
+            Conjugated::Some(v) if !dual && v.len() > 6 => Some(&v[..6]),
+            Conjugated::Some(v) => Some(v),
             _ => None,
         }
     }
 }
+
+// Deponents are middle or passive in form but active in meaning. Which kind
+// a verb is depends on its aorist: ἐβουληθην makes βουλομαι a passive
+// deponent, ἐδεξαμην makes δεχομαι a middle one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Deponency {
+    Middle,
+    Passive,
+    None,
+}
+
+impl fmt::Display for Deponency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Deponency::Middle => write!(f, "middle deponent"),
+            Deponency::Passive => write!(f, "passive deponent"),
+            Deponency::None => write!(f, ""),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MovableNu {
+    Never,
+    Always,
+    Parenthesised,
+}
+
+impl MovableNu {
+    pub fn apply(self, part: &mut String) {
+        match self {
+            MovableNu::Never => {}
+            MovableNu::Always => part.push('ν'),
+            MovableNu::Parenthesised => part.push_str("(ν)"),
+        }
+    }
+}
+
+// Attic is the default. Homer leaves contract verbs open, has Ionic endings
+// and iteratives in -σκον, and may leave the augment off. Koine, the Greek of
+// the New Testament, gives strong aorists weak endings and has little use for
+// the optative.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Dialect {
+    Attic,
+    Homeric { augment: bool },
+    Koine,
+}
@@ -1,6 +1,19 @@
-use super::types::{Conjugated, TenseVoiceMoodVariant};
+use super::accent::{self, Nominative};
+use super::augment::{self, Lexicon};
+use super::contract::Contraction;
+use super::irregular;
+use super::participle::{
+    decline, Declined, ALPHA_PURE, AORIST_PASSIVE, MIDDLE, OMICRON_ROOT, THEMATIC, UPSILON_ROOT,
+    WEAK_AORIST,
+};
+use super::preverb;
+use super::types::{Conjugated, Deponency, Dialect, MovableNu, TenseVoiceMoodVariant};
 use serde::Deserialize;
 
+// Present stems whose ι or υ is long by nature, as the ends of simple stems
+// so that compounds are caught too
+const LONG_STEMS: [&str; 6] = ["ῥιπτ", "ριπτ", "κριν", "τριβ", "πνιγ", "ψυχ"];
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "PascalCase")]
 pub struct VerbEntry {
@@ -9,14 +22,36 @@ pub struct VerbEntry {
     aorist: Option<String>,
     #[serde(rename = "Aorist_Passive")]
     aorist_passive: Option<String>,
+    #[serde(default)]
+    perfect: Option<String>,
+    #[serde(default, rename = "Perfect_Middle")]
+    perfect_middle: Option<String>,
 }
 
 impl VerbEntry {
+    // A present in -μαι marks a deponent; an aorist in -ην other than -μην
+    // (ἐβουληθην, ἐφανην) makes it a passive one.
+    pub fn deponency(&self) -> Deponency {
+        match &self.present {
+            Some(pres) if pres.ends_with("μαι") => {
+                let passive = match &self.aorist {
+                    Some(aor) => aor
+                        .split('/')
+                        .any(|aor| aor.ends_with("ην") && !aor.ends_with("μην")),
+                    None => false,
+                };
+                if passive {
+                    Deponency::Passive
+                } else {
+                    Deponency::Middle
+                }
+            }
+            _ => Deponency::None,
+        }
+    }
+
     pub fn check_alternates(&self) -> VerbAlternates {
-        let pres = match &self.present {
-            Some(v) => Some(v.to_string()),
-            None => None,
-        };
+        let pres = self.present.as_ref().map(|v| v.to_string());
         let (fut, fut_alt) = match &self.future {
             Some(v) => {
                 if v.contains("/") {
@@ -50,6 +85,28 @@ impl VerbEntry {
             }
             _ => (None, None),
         };
+        let (perf, perf_alt) = match &self.perfect {
+            Some(v) => {
+                if v.contains("/") {
+                    let v_s: Vec<&str> = v.split("/").collect();
+                    (Some(v_s[0].to_string()), Some(v_s[1].to_string()))
+                } else {
+                    (Some(v.to_string()), None)
+                }
+            }
+            _ => (None, None),
+        };
+        let (perf_mid, perf_mid_alt) = match &self.perfect_middle {
+            Some(v) => {
+                if v.contains("/") {
+                    let v_s: Vec<&str> = v.split("/").collect();
+                    (Some(v_s[0].to_string()), Some(v_s[1].to_string()))
+                } else {
+                    (Some(v.to_string()), None)
+                }
+            }
+            _ => (None, None),
+        };
         VerbAlternates {
             present: pres,
             future: fut,
@@ -58,6 +115,11 @@ impl VerbEntry {
             aorist_alt: aor_alt,
             aorist_passive: aor_pass,
             aorist_passive_alt: aor_pass_alt,
+            perfect: perf,
+            perfect_alt: perf_alt,
+            perfect_middle: perf_mid,
+            perfect_middle_alt: perf_mid_alt,
+            deponency: self.deponency(),
         }
     }
 }
@@ -71,14 +133,137 @@ pub struct VerbAlternates {
     aorist_alt: Option<String>,
     aorist_passive: Option<String>,
     aorist_passive_alt: Option<String>,
+    perfect: Option<String>,
+    perfect_alt: Option<String>,
+    perfect_middle: Option<String>,
+    perfect_middle_alt: Option<String>,
+    deponency: Deponency,
 }
 
 impl VerbAlternates {
-    pub fn parse(&self) -> VerbStemSet {
+    // The stem is stored without the final consonant of the cluster, which
+    // changes with each ending: γεγρα-μμαι, γεγρα-ψαι, γεγρα-πται.
+    fn parse_perf_mid(pp: &str) -> Option<TenseVoiceMoodVariant> {
+        let mut tmp = pp.to_string();
+        if tmp.ends_with("μμαι") {
+            for _ in 0..4 {
+                tmp.pop();
+            }
+            Some(TenseVoiceMoodVariant::PerfMidIndLab(tmp))
+        } else if tmp.ends_with("γμαι") {
+            for _ in 0..4 {
+                tmp.pop();
+            }
+            Some(TenseVoiceMoodVariant::PerfMidIndVel(tmp))
+        } else if tmp.ends_with("σμαι") {
+            for _ in 0..4 {
+                tmp.pop();
+            }
+            Some(TenseVoiceMoodVariant::PerfMidIndDen(tmp))
+        } else if tmp.ends_with("λμαι") || tmp.ends_with("ρμαι") {
+            for _ in 0..3 {
+                tmp.pop();
+            }
+            Some(TenseVoiceMoodVariant::PerfMidIndLiq(tmp))
+        } else if tmp.ends_with("μαι") {
+            for _ in 0..3 {
+                tmp.pop();
+            }
+            Some(TenseVoiceMoodVariant::PerfMidIndVow(tmp))
+        } else {
+            None
+        }
+    }
+
+    // εδωκα, εθηκα, ἡκα: the κ belongs to the singular only
+    fn has_kappa_aorist(pres: &Option<TenseVoiceMoodVariant>) -> bool {
+        matches!(
+            pres,
+            Some(TenseVoiceMoodVariant::PresActIndMiOmi(_))
+                | Some(TenseVoiceMoodVariant::PresActIndMiEps(_))
+        )
+    }
+
+    // ἐγνων and ἐδυν can only be root aorists. An -ην aorist is one too
+    // (ἐβην, ἐστην) unless it is a middle in -μην, the θ marks it passive,
+    // or the present is deponent, as with φαινομαι, ἐφανην.
+    fn is_root_aorist(pres: &Option<TenseVoiceMoodVariant>, pp: &str) -> bool {
+        let active_present = matches!(
+            pres,
+            Some(TenseVoiceMoodVariant::PresActIndReg(_))
+                | Some(TenseVoiceMoodVariant::PresActIndAlp(_))
+                | Some(TenseVoiceMoodVariant::PresActIndEps(_))
+                | Some(TenseVoiceMoodVariant::PresActIndOmi(_))
+                | Some(TenseVoiceMoodVariant::PresActIndMiOmi(_))
+                | Some(TenseVoiceMoodVariant::PresActIndMiEps(_))
+                | Some(TenseVoiceMoodVariant::PresActIndMiAlp(_))
+                | Some(TenseVoiceMoodVariant::PresActIndMiUps(_))
+        );
+        pp.ends_with("ων")
+            || pp.ends_with("υν")
+            || (pp.ends_with("ην")
+                && !pp.ends_with("μην")
+                && !pp.ends_with("θην")
+                && active_present)
+    }
+
+    // The ι or υ before the σ of the future and weak aorist is long when the
+    // present ends in it, λυω, ἐλῡσα, and in stems long by nature
+    // throughout: ἐρρῑψα, ἐκρῑνα
+    fn long_vowel(pres: &Option<TenseVoiceMoodVariant>, stem: &str) -> String {
+        let pres = pres
+            .as_ref()
+            .map(|pres| pres.to_string())
+            .unwrap_or_default();
+        let mut chars: Vec<char> = stem.chars().collect();
+        let n = chars.len();
+        let at = if LONG_STEMS.iter().any(|long| pres.ends_with(long)) {
+            chars.iter().rposition(|c| matches!(c, 'ι' | 'υ'))
+        } else if n > 1 && chars[n - 1] == 'σ' && pres.ends_with(chars[n - 2]) {
+            Some(n - 2)
+        } else {
+            None
+        };
+        if let Some(at) = at {
+            let diphthong = at > 0 && matches!(chars[at - 1], 'α' | 'ε' | 'η' | 'ο' | 'υ');
+            if matches!(chars[at], 'ι' | 'υ') && !diphthong {
+                chars[at] = accent::long(chars[at]);
+            }
+        }
+        chars.into_iter().collect()
+    }
+
+    fn lengthen(
+        pres: &Option<TenseVoiceMoodVariant>,
+        stem: Option<TenseVoiceMoodVariant>,
+    ) -> Option<TenseVoiceMoodVariant> {
+        match stem {
+            Some(TenseVoiceMoodVariant::FutActIndReg(stm)) => Some(
+                TenseVoiceMoodVariant::FutActIndReg(VerbAlternates::long_vowel(pres, &stm)),
+            ),
+            Some(TenseVoiceMoodVariant::FutMidIndReg(stm)) => Some(
+                TenseVoiceMoodVariant::FutMidIndReg(VerbAlternates::long_vowel(pres, &stm)),
+            ),
+            Some(TenseVoiceMoodVariant::AorActIndWk(stm)) => Some(
+                TenseVoiceMoodVariant::AorActIndWk(VerbAlternates::long_vowel(pres, &stm)),
+            ),
+            Some(TenseVoiceMoodVariant::AorMidIndWk(stm)) => Some(
+                TenseVoiceMoodVariant::AorMidIndWk(VerbAlternates::long_vowel(pres, &stm)),
+            ),
+            other => other,
+        }
+    }
+
+    pub fn parse<'l>(&self, lexicon: &'l Lexicon, dialect: Dialect) -> VerbStemSet<'l> {
         let pres: Option<TenseVoiceMoodVariant> = match &self.present {
             Some(pp) => {
                 let mut tmp = pp.to_string();
-                if tmp.ends_with("αω") {
+                if irregular::find_present(&tmp).is_some() {
+                    if tmp == "εἰμι" {
+                        eprintln!("Taking εἰμι as εἰμί \"be\"; write εἶμι for \"go\"");
+                    }
+                    Some(TenseVoiceMoodVariant::PresIndIrr(tmp))
+                } else if tmp.ends_with("αω") {
                     tmp.pop();
                     tmp.pop();
                     Some(TenseVoiceMoodVariant::PresActIndAlp(tmp))
@@ -120,6 +305,28 @@ impl VerbAlternates {
                     tmp.pop();
                     tmp.pop();
                     Some(TenseVoiceMoodVariant::PresPassIndReg(tmp))
+                } else if tmp.ends_with("ωμι") {
+                    tmp.pop();
+                    tmp.pop();
+                    tmp.pop();
+                    Some(TenseVoiceMoodVariant::PresActIndMiOmi(tmp))
+                } else if tmp.ends_with("θημι") || tmp.ends_with("ιημι") || tmp.ends_with("ἱημι")
+                {
+                    // τιθημι and ἱημι have ε in the short stem, ἱστημι has α
+                    tmp.pop();
+                    tmp.pop();
+                    tmp.pop();
+                    Some(TenseVoiceMoodVariant::PresActIndMiEps(tmp))
+                } else if tmp.ends_with("ημι") {
+                    tmp.pop();
+                    tmp.pop();
+                    tmp.pop();
+                    Some(TenseVoiceMoodVariant::PresActIndMiAlp(tmp))
+                } else if tmp.ends_with("υμι") {
+                    tmp.pop();
+                    tmp.pop();
+                    tmp.pop();
+                    Some(TenseVoiceMoodVariant::PresActIndMiUps(tmp))
                 } else {
                     None
                 }
@@ -129,7 +336,9 @@ impl VerbAlternates {
         let fut: Option<TenseVoiceMoodVariant> = match &self.future {
             Some(pp) => {
                 let mut tmp = pp.to_string();
-                if tmp.ends_with("σω") || tmp.ends_with("ξω") || tmp.ends_with("ψω") {
+                if irregular::find_future(&tmp).is_some() {
+                    Some(TenseVoiceMoodVariant::FutIndIrr(tmp))
+                } else if tmp.ends_with("σω") || tmp.ends_with("ξω") || tmp.ends_with("ψω") {
                     tmp.pop();
                     Some(TenseVoiceMoodVariant::FutActIndReg(tmp))
                 } else if tmp.ends_with("θησομαι") {
@@ -166,7 +375,9 @@ impl VerbAlternates {
         let fut2: Option<TenseVoiceMoodVariant> = match &self.future_alt {
             Some(pp) => {
                 let mut tmp = pp.to_string();
-                if tmp.ends_with("σω") || tmp.ends_with("ξω") {
+                if irregular::find_future(&tmp).is_some() {
+                    Some(TenseVoiceMoodVariant::FutIndIrr(tmp))
+                } else if tmp.ends_with("σω") || tmp.ends_with("ξω") {
                     tmp.pop();
                     Some(TenseVoiceMoodVariant::FutActIndReg(tmp))
                 } else if tmp.ends_with("θησομαι") {
@@ -200,7 +411,14 @@ impl VerbAlternates {
         let aor: Option<TenseVoiceMoodVariant> = match &self.aorist {
             Some(pp) => {
                 let mut tmp = pp.to_string();
-                if tmp.ends_with("α") {
+                if tmp.ends_with("κα") && VerbAlternates::has_kappa_aorist(&pres) {
+                    tmp.pop();
+                    tmp.pop();
+                    Some(TenseVoiceMoodVariant::AorActIndKap(tmp))
+                } else if VerbAlternates::is_root_aorist(&pres, &tmp) {
+                    tmp.pop();
+                    Some(TenseVoiceMoodVariant::AorActIndRoot(tmp))
+                } else if tmp.ends_with("α") {
                     tmp.pop();
                     Some(TenseVoiceMoodVariant::AorActIndWk(tmp))
                 } else if tmp.ends_with("αμην") {
@@ -215,10 +433,14 @@ impl VerbAlternates {
                     tmp.pop();
                     tmp.pop();
                     Some(TenseVoiceMoodVariant::AorMidIndStr(tmp))
-                } else if tmp.ends_with("ην") {
+                } else if tmp.ends_with("θην") {
                     tmp.pop();
                     tmp.pop();
                     Some(TenseVoiceMoodVariant::AorPassIndWk(tmp))
+                } else if tmp.ends_with("ην") {
+                    tmp.pop();
+                    tmp.pop();
+                    Some(TenseVoiceMoodVariant::AorPassIndStr(tmp))
                 } else if tmp.ends_with("ον") {
                     tmp.pop();
                     tmp.pop();
@@ -232,7 +454,14 @@ impl VerbAlternates {
         let aor2: Option<TenseVoiceMoodVariant> = match &self.aorist_alt {
             Some(pp) => {
                 let mut tmp = pp.to_string();
-                if tmp.ends_with("α") {
+                if tmp.ends_with("κα") && VerbAlternates::has_kappa_aorist(&pres) {
+                    tmp.pop();
+                    tmp.pop();
+                    Some(TenseVoiceMoodVariant::AorActIndKap(tmp))
+                } else if VerbAlternates::is_root_aorist(&pres, &tmp) {
+                    tmp.pop();
+                    Some(TenseVoiceMoodVariant::AorActIndRoot(tmp))
+                } else if tmp.ends_with("α") {
                     tmp.pop();
                     Some(TenseVoiceMoodVariant::AorActIndWk(tmp))
                 } else if tmp.ends_with("αμην") {
@@ -247,10 +476,14 @@ impl VerbAlternates {
                     tmp.pop();
                     tmp.pop();
                     Some(TenseVoiceMoodVariant::AorMidIndStr(tmp))
-                } else if tmp.ends_with("ην") {
+                } else if tmp.ends_with("θην") {
                     tmp.pop();
                     tmp.pop();
                     Some(TenseVoiceMoodVariant::AorPassIndWk(tmp))
+                } else if tmp.ends_with("ην") {
+                    tmp.pop();
+                    tmp.pop();
+                    Some(TenseVoiceMoodVariant::AorPassIndStr(tmp))
                 } else if tmp.ends_with("ον") {
                     tmp.pop();
                     tmp.pop();
@@ -263,11 +496,16 @@ impl VerbAlternates {
         };
         let ap: Option<TenseVoiceMoodVariant> = match &self.aorist_passive {
             Some(pp) => {
-                if pp.ends_with("ην") {
+                if pp.ends_with("θην") {
                     let mut tmp = pp.to_string();
                     tmp.pop();
                     tmp.pop();
                     Some(TenseVoiceMoodVariant::AorPassIndWk(tmp))
+                } else if pp.ends_with("ην") {
+                    let mut tmp = pp.to_string();
+                    tmp.pop();
+                    tmp.pop();
+                    Some(TenseVoiceMoodVariant::AorPassIndStr(tmp))
                 } else {
                     None
                 }
@@ -276,17 +514,64 @@ impl VerbAlternates {
         };
         let ap2: Option<TenseVoiceMoodVariant> = match &self.aorist_passive_alt {
             Some(pp) => {
-                if pp.ends_with("ην") {
+                if pp.ends_with("θην") {
                     let mut tmp = pp.to_string();
                     tmp.pop();
                     tmp.pop();
                     Some(TenseVoiceMoodVariant::AorPassIndWk(tmp))
+                } else if pp.ends_with("ην") {
+                    let mut tmp = pp.to_string();
+                    tmp.pop();
+                    tmp.pop();
+                    Some(TenseVoiceMoodVariant::AorPassIndStr(tmp))
+                } else {
+                    None
+                }
+            }
+            None => None,
+        };
+        let perf: Option<TenseVoiceMoodVariant> = match &self.perfect {
+            Some(pp) => {
+                let mut tmp = pp.to_string();
+                if tmp.ends_with("κα") {
+                    tmp.pop();
+                    Some(TenseVoiceMoodVariant::PerfActIndKap(tmp))
+                } else if tmp.ends_with("α") {
+                    tmp.pop();
+                    Some(TenseVoiceMoodVariant::PerfActIndStr(tmp))
+                } else {
+                    None
+                }
+            }
+            None => None,
+        };
+        let perf2: Option<TenseVoiceMoodVariant> = match &self.perfect_alt {
+            Some(pp) => {
+                let mut tmp = pp.to_string();
+                if tmp.ends_with("κα") {
+                    tmp.pop();
+                    Some(TenseVoiceMoodVariant::PerfActIndKap(tmp))
+                } else if tmp.ends_with("α") {
+                    tmp.pop();
+                    Some(TenseVoiceMoodVariant::PerfActIndStr(tmp))
                 } else {
                     None
                 }
             }
             None => None,
         };
+        let perf_mid = match &self.perfect_middle {
+            Some(pp) => VerbAlternates::parse_perf_mid(pp),
+            None => None,
+        };
+        let perf_mid2 = match &self.perfect_middle_alt {
+            Some(pp) => VerbAlternates::parse_perf_mid(pp),
+            None => None,
+        };
+        let fut = VerbAlternates::lengthen(&pres, fut);
+        let fut2 = VerbAlternates::lengthen(&pres, fut2);
+        let aor = VerbAlternates::lengthen(&pres, aor);
+        let aor2 = VerbAlternates::lengthen(&pres, aor2);
         VerbStemSet {
             pres_stem: pres,
             fut_stem: fut,
@@ -295,12 +580,19 @@ impl VerbAlternates {
             aorist_stem_alt: aor2,
             aor_pass_stem: ap,
             aor_pass_stem_alt: ap2,
+            perf_stem: perf,
+            perf_stem_alt: perf2,
+            perf_mid_stem: perf_mid,
+            perf_mid_stem_alt: perf_mid2,
+            deponency: self.deponency,
+            lexicon,
+            dialect,
         }
     }
 }
 
 #[derive(Debug)]
-pub struct VerbStemSet {
+pub struct VerbStemSet<'l> {
     pres_stem: Option<TenseVoiceMoodVariant>,
     fut_stem: Option<TenseVoiceMoodVariant>,
     fut_stem_alt: Option<TenseVoiceMoodVariant>,
@@ -308,709 +600,2692 @@ pub struct VerbStemSet {
     aorist_stem_alt: Option<TenseVoiceMoodVariant>,
     aor_pass_stem: Option<TenseVoiceMoodVariant>,
     aor_pass_stem_alt: Option<TenseVoiceMoodVariant>,
+    perf_stem: Option<TenseVoiceMoodVariant>,
+    perf_stem_alt: Option<TenseVoiceMoodVariant>,
+    perf_mid_stem: Option<TenseVoiceMoodVariant>,
+    perf_mid_stem_alt: Option<TenseVoiceMoodVariant>,
+    deponency: Deponency,
+    lexicon: &'l Lexicon,
+    dialect: Dialect,
 }
 
-impl VerbStemSet {
-    fn aug_and_stem<'a>(
-        opt_aor: &Option<TenseVoiceMoodVariant>,
-        mut stem: &'a str,
-    ) -> (&'a str, &'a str) {
-        let aug: &str = match stem {
-            stm if stm.starts_with("ἀμφι") => {
-                stem = stem.splitn(2, "ι").collect::<Vec<&str>>()[1];
-                "ἀμφε"
-            }
-            stm if stm.starts_with("ἀνα") => match opt_aor {
-                Some(aor) => {
-                    if aor.first_char() == "ἠ" {
-                        stem = stem.splitn(2, "ἀ").collect::<Vec<&str>>()[1];
-                        "ἠ"
-                    } else {
-                        stem = stem.splitn(2, "α").collect::<Vec<&str>>()[1];
-                        "ἀνε"
-                    }
-                }
-                None => "ἠ",
-            },
-            stm if stm.starts_with("ἀντι") => {
-                stem = stem.splitn(2, "ι").collect::<Vec<&str>>()[1];
-                "ἀντε"
-            }
-            stm if stm.starts_with("ἀπο") => {
-                stem = stem.splitn(2, "ο").collect::<Vec<&str>>()[1];
-                "ἀπε"
-            }
-            stm if stm.starts_with("ἀφι") => "",
-            stm if stm.starts_with("αἰ") => {
-                stem = stem.splitn(2, "ἰ").collect::<Vec<&str>>()[1];
-                "ᾐ"
-            }
-            stm if stm.starts_with("αἱ") => {
-                stem = stem.splitn(2, "ἱ").collect::<Vec<&str>>()[1];
-                "ᾑ"
-            }
-            stm if stm.starts_with("ἀ") => {
-                stem = stem.splitn(2, "ἀ").collect::<Vec<&str>>()[1];
-                "ἠ"
-            }
-            stm if stm.starts_with("ἁ") => {
-                stem = stem.splitn(2, "ἁ").collect::<Vec<&str>>()[1];
-                "ἡ"
-            }
-            stm if stm.starts_with("δια") => {
-                stem = stem.splitn(2, "α").collect::<Vec<&str>>()[1];
-                "διε"
-            }
-            stm if stm.starts_with("διε") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                "διει"
-            }
-            stm if stm.starts_with("διο") => {
-                stem = stem.splitn(2, "ο").collect::<Vec<&str>>()[1];
-                "διω"
-            }
-            stm if stm.starts_with("εἰσ") => {
-                stem = stem.splitn(2, "σ").collect::<Vec<&str>>()[1];
-                "εἰσε"
-            }
-            stm if stm.starts_with("ἐκ") => {
-                stem = stem.splitn(2, "κ").collect::<Vec<&str>>()[1];
-                "ἐξε"
-            }
-            stm if stm.starts_with("ἐμ") => {
-                stem = stem.splitn(2, "μ").collect::<Vec<&str>>()[1];
-                "ἐνε"
-            }
-            stm if stm.starts_with("ἐγ") => {
-                stem = stem.splitn(2, "γ").collect::<Vec<&str>>()[1];
-                "ἐνε"
-            }
-            stm if stm.starts_with("ἐνε") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                "ἐνει"
-            }
-            stm if stm.starts_with("ἐνα") => {
-                stem = stem.splitn(2, "α").collect::<Vec<&str>>()[1];
-                "ἐνη"
-            }
-            stm if stm.starts_with("ἐν") => {
-                stem = stem.splitn(2, "ν").collect::<Vec<&str>>()[1];
-                "ἐνε"
-            }
-            stm if stm.starts_with("ἐπε") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                "ἐπει"
-            }
-            stm if stm.starts_with("ἐπα") => {
-                stem = stem.splitn(2, "α").collect::<Vec<&str>>()[1];
-                "ἐπη"
-            }
-            stm if stm.starts_with("ἐπι") => {
-                stem = stem.splitn(2, "ι").collect::<Vec<&str>>()[1];
-                "ἐπε"
-            }
-            stm if stm.starts_with("εὐ") => {
-                stem = stem.splitn(2, "ὐ").collect::<Vec<&str>>()[1];
-                "ηὐ"
-            }
-            stm if stm.starts_with("εὑ") => {
-                stem = stem.splitn(2, "ὑ").collect::<Vec<&str>>()[1];
-                "ηὑ"
+impl<'l> VerbStemSet<'l> {
+    // A compound is augmented between its preverb and the simple verb,
+    // ἀπο-βαλλ, ἀπ-ε-βαλλ. The part of the stem left unchanged is returned
+    // after the augmented part.
+    fn aug_and_stem<'a>(&self, stem: &'a str) -> (String, &'a str) {
+        if !self.augmented() {
+            return (String::new(), stem);
+        }
+        let aor = self.aorist_stem.as_ref().map(|aor| aor.to_string());
+        match preverb::split(stem, aor.as_deref()) {
+            Some(compound) => {
+                let aor_initial = aor
+                    .as_deref()
+                    .and_then(|aor| preverb::split(aor, Some(stem)))
+                    .map(|aor| VerbStemSet::initial(&aor.simplex));
+                let (aug, rest) = self.augment(aor_initial, &compound.simplex);
+                let augmented = compound.rejoin(&format!("{}{}", aug, rest));
+                let keep = if stem.ends_with(rest) && augmented.ends_with(rest) {
+                    rest.len()
+                } else {
+                    0
+                };
+                (
+                    augmented[..augmented.len() - keep].to_string(),
+                    &stem[stem.len() - keep..],
+                )
             }
-            stm if stm.starts_with("ἐ") => {
-                stem = stem.splitn(2, "ἐ").collect::<Vec<&str>>()[1];
-                match opt_aor {
-                    Some(aor) => match &aor {
-                        &TenseVoiceMoodVariant::AorActIndWk(_)
-                        | &TenseVoiceMoodVariant::AorMidIndWk(_)
-                        | &TenseVoiceMoodVariant::AorPassIndWk(_) => {
-                            if aor.first_char() == "ἠ" {
-                                "ἠ"
-                            } else {
-                                "εἰ"
-                            }
-                        }
-                        _ => "ἠ",
-                    },
-                    None => "ἠ",
-                }
+            None => {
+                let aor_initial = self.aorist_stem.as_ref().map(|aor| aor.first_char());
+                self.augment(aor_initial, stem)
             }
-            stm if stm.starts_with("ἑ") => {
-                stem = stem.splitn(2, "ἑ").collect::<Vec<&str>>()[1];
-                match opt_aor {
-                    Some(aor) => {
-                        if aor.first_char() == "ἡ" {
-                            "ἡ"
-                        } else {
-                            "εἱ"
-                        }
-                    }
-                    None => "ἡ",
+        }
+    }
+
+    // The recessive accent of a compound goes no further back than the last
+    // syllable of its preverb (ἀπόδος, ἄπαγε), and in an augmented form no
+    // further than the augment (ἀπῆλθον, παρέσχον). This is how many
+    // syllables it may not reach.
+    fn accent_floor(
+        &self,
+        stem: &str,
+        other: &Option<TenseVoiceMoodVariant>,
+        augmented: bool,
+    ) -> usize {
+        let other = other.as_ref().map(|other| other.to_string());
+        match preverb::split(stem, other.as_deref()) {
+            Some(compound) => {
+                let preverbs = accent::syllable_count(stem)
+                    .saturating_sub(accent::syllable_count(&compound.simplex));
+                if augmented && self.augmented() {
+                    preverbs
+                } else {
+                    preverbs.saturating_sub(1)
                 }
             }
-            stm if stm.starts_with("καταγ") => {
-                stem = stem.splitn(2, "γ").collect::<Vec<&str>>()[1];
-                "κατηγ"
-            }
-            stm if stm.starts_with("κατα") => {
-                stem = stem.splitn(2, "τα").collect::<Vec<&str>>()[1];
-                "κατε"
-            }
-            stm if stm.starts_with("μεταγ") => {
-                stem = stem.splitn(2, "α").collect::<Vec<&str>>()[1];
-                "μετη"
-            }
-            stm if stm.starts_with("μετα") => {
-                stem = stem.splitn(2, "α").collect::<Vec<&str>>()[1];
-                "μετε"
-            }
-            stm if stm.starts_with("παραγ") => {
-                stem = stem.splitn(2, "ρα").collect::<Vec<&str>>()[1];
-                "παρη"
-            }
-            stm if stm.starts_with("παρα") => {
-                stem = stem.splitn(2, "ρα").collect::<Vec<&str>>()[1];
-                "παρε"
-            }
-            stm if stm.starts_with("παρε") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                "παρει"
-            }
-            stm if stm.starts_with("περι") => {
-                stem = stem.splitn(2, "ι").collect::<Vec<&str>>()[1];
-                "περιε"
-            }
-            stm if stm.starts_with("περιαγ") => {
-                stem = stem.splitn(2, "α").collect::<Vec<&str>>()[1];
-                "περιη"
-            }
-            stm if stm.starts_with("προσ") => {
-                stem = stem.splitn(2, "σ").collect::<Vec<&str>>()[1];
-                "προσε"
-            }
-            stm if stm.starts_with("προ") => {
-                stem = stem.splitn(2, "ο").collect::<Vec<&str>>()[1];
-                "πρου"
-            }
-            stm if stm.starts_with("συγ") => {
-                stem = stem.splitn(2, "γ").collect::<Vec<&str>>()[1];
-                "συνε"
-            }
-            stm if stm.starts_with("συλλ") => {
-                stem = stem.splitn(2, "λ").collect::<Vec<&str>>()[1];
-                "συνε"
-            }
-            stm if stm.starts_with("συμ") => {
-                stem = stem.splitn(2, "μ").collect::<Vec<&str>>()[1];
-                "συνε"
-            }
-            stm if stm.starts_with("συρ") => {
-                stem = stem.splitn(2, "ρ").collect::<Vec<&str>>()[1];
-                "συνε"
-            }
-            stm if stm.starts_with("συσ") => {
-                stem = stem.splitn(2, "υσ").collect::<Vec<&str>>()[1];
-                "συνε"
-            }
-            stm if stm.starts_with("οἰ") => {
-                stem = stem.splitn(2, "ἰ").collect::<Vec<&str>>()[1];
-                "ᾠ"
-            }
-            stm if stm.starts_with("οἱ") => {
-                stem = stem.splitn(2, "ἱ").collect::<Vec<&str>>()[1];
-                "ᾡ"
-            }
-            stm if stm.starts_with("ὀ") => {
-                stem = stem.splitn(2, "ὀ").collect::<Vec<&str>>()[1];
-                "ὠ"
-            }
-            stm if stm.starts_with("ὁ") => {
-                stem = stem.splitn(2, "ὁ").collect::<Vec<&str>>()[1];
-                "ὡ"
+            None => 0,
+        }
+    }
+
+    // Aorist stems come augmented from the principal parts
+    fn aorist_floor(&self, opt_aor: &Option<TenseVoiceMoodVariant>) -> usize {
+        match opt_aor {
+            Some(aor) => self.accent_floor(&aor.to_string(), &self.pres_stem, true),
+            None => 0,
+        }
+    }
+
+    fn pluperfect_floor(&self, opt_perf: &Option<TenseVoiceMoodVariant>) -> usize {
+        match opt_perf {
+            Some(perf) => {
+                let perf = perf.to_string();
+                let (aug, rest) = self.plup_aug_and_stem(&perf);
+                self.accent_floor(&format!("{}{}", aug, rest), opt_perf, true)
             }
-            stm if stm.starts_with("ὑπο") => {
-                stem = stem.splitn(2, "ο").collect::<Vec<&str>>()[1];
-                "ο"
+            None => 0,
+        }
+    }
+
+    // Participles of the μι presents, of the strong, κ- and root aorists and
+    // of the aorist passive are oxytone: τιθείς, λαβών, λυθείς
+    fn nominative(opt_stem: &Option<TenseVoiceMoodVariant>, floor: usize) -> Nominative {
+        match opt_stem {
+            Some(TenseVoiceMoodVariant::PresActIndMiOmi(_))
+            | Some(TenseVoiceMoodVariant::PresActIndMiEps(_))
+            | Some(TenseVoiceMoodVariant::PresActIndMiAlp(_))
+            | Some(TenseVoiceMoodVariant::PresActIndMiUps(_))
+            | Some(TenseVoiceMoodVariant::PresIndIrr(_))
+            | Some(TenseVoiceMoodVariant::FutIndIrr(_))
+            | Some(TenseVoiceMoodVariant::AorActIndStr(_))
+            | Some(TenseVoiceMoodVariant::AorActIndKap(_))
+            | Some(TenseVoiceMoodVariant::AorActIndRoot(_))
+            | Some(TenseVoiceMoodVariant::AorPassIndWk(_))
+            | Some(TenseVoiceMoodVariant::AorPassIndStr(_)) => Nominative::Oxytone,
+            _ => Nominative::Recessive(floor),
+        }
+    }
+
+    // Augmented forms are accented first, each against its own augment; the
+    // rest of the verb then keeps the accent off the preverb
+    fn accent(&self, verb: &mut Verb) {
+        let pres = self
+            .pres_stem
+            .as_ref()
+            .map(|pres| pres.to_string())
+            .unwrap_or_default();
+        let floor = self.accent_floor(&pres, &self.aorist_stem, false);
+        let (aug, rest) = self.aug_and_stem(&pres);
+        let imperfect = self.accent_floor(&format!("{}{}", aug, rest), &self.pres_stem, true);
+        for conj in [&mut verb.iai, &mut verb.ipi] {
+            conj.accent(false, imperfect);
+        }
+        let aorist = self.aorist_floor(&self.aorist_stem);
+        let aorist_alt = self.aorist_floor(&self.aorist_stem_alt);
+        let passive = self.aorist_floor(&self.aor_pass_stem);
+        let passive_alt = self.aorist_floor(&self.aor_pass_stem_alt);
+        let pluperfect = self.pluperfect_floor(&self.perf_stem);
+        let pluperfect_alt = self.pluperfect_floor(&self.perf_stem_alt);
+        let pluperfect_mid = self.pluperfect_floor(&self.perf_mid_stem);
+        let pluperfect_mid_alt = self.pluperfect_floor(&self.perf_mid_stem_alt);
+        for (conj, floor) in [
+            (&mut verb.aai, aorist),
+            (&mut verb.ami, aorist),
+            (&mut verb.aai2, aorist_alt),
+            (&mut verb.ami2, aorist_alt),
+            (&mut verb.api, passive),
+            (&mut verb.api2, passive_alt),
+            (&mut verb.lai, pluperfect),
+            (&mut verb.lai2, pluperfect_alt),
+            (&mut verb.lmi, pluperfect_mid),
+            (&mut verb.lmi2, pluperfect_mid_alt),
+        ] {
+            conj.accent(false, floor);
+        }
+        verb.accent(floor);
+
+        let middle = Nominative::Recessive(floor);
+        let pres = VerbStemSet::nominative(&self.pres_stem, floor);
+        let fut = VerbStemSet::nominative(&self.fut_stem, floor);
+        let fut_alt = VerbStemSet::nominative(&self.fut_stem_alt, floor);
+        let aor = VerbStemSet::nominative(&self.aorist_stem, floor);
+        let aor_alt = VerbStemSet::nominative(&self.aorist_stem_alt, floor);
+        for (part, nominative) in [
+            (&mut verb.pap, pres),
+            (&mut verb.ppp, middle),
+            (&mut verb.fap, fut),
+            (&mut verb.fap2, fut_alt),
+            (&mut verb.fmp, middle),
+            (&mut verb.fmp2, middle),
+            (&mut verb.fpp, middle),
+            (&mut verb.fpp2, middle),
+            (&mut verb.aap, aor),
+            (&mut verb.aap2, aor_alt),
+            (&mut verb.amp, middle),
+            (&mut verb.amp2, middle),
+            (&mut verb.app, Nominative::Oxytone),
+            (&mut verb.app2, Nominative::Oxytone),
+            (&mut verb.teos, Nominative::Penult),
+            (&mut verb.teos2, Nominative::Penult),
+            (&mut verb.tos, Nominative::Oxytone),
+            (&mut verb.tos2, Nominative::Oxytone),
+        ] {
+            part.accent(nominative);
+        }
+    }
+
+    // Homer does not contract
+    fn contraction(&self, stmtype: &TenseVoiceMoodVariant) -> Contraction {
+        match self.dialect {
+            Dialect::Homeric { .. } => Contraction::of(stmtype).uncontracted(),
+            _ => Contraction::of(stmtype),
+        }
+    }
+
+    // Koine gives the strong aorist the endings of the weak: ἠλθα, ἠλθαν
+    fn strong_aorist_endings(&self) -> [&'static str; 8] {
+        match self.dialect {
+            Dialect::Koine => ["α", "ας", "ε", "αμεν", "ατε", "αν", "ατον", "ατην"],
+            _ => ["ον", "ες", "ε", "ομεν", "ετε", "ον", "ετον", "ετην"],
+        }
+    }
+
+    fn is_active_aorist(opt_aor: &Option<TenseVoiceMoodVariant>) -> bool {
+        matches!(
+            opt_aor,
+            Some(TenseVoiceMoodVariant::AorActIndWk(_))
+                | Some(TenseVoiceMoodVariant::AorActIndStr(_))
+                | Some(TenseVoiceMoodVariant::AorActIndKap(_))
+                | Some(TenseVoiceMoodVariant::AorActIndRoot(_))
+        )
+    }
+
+    fn augmented(&self) -> bool {
+        self.dialect != Dialect::Homeric { augment: false }
+    }
+
+    // Homer's unaugmented aorists come from the principal parts, which are
+    // augmented: the augment comes off the stem where a form starts with it,
+    // and off the whole form otherwise (ἐθεμεν beside ἐθηκα)
+    fn drop_augment(&self, opt_aor: &Option<TenseVoiceMoodVariant>, conj: &mut Conjugated) {
+        let stem = match opt_aor {
+            Some(stmtype) => stmtype.to_string(),
+            None => return,
+        };
+        let unaugmented = self.remove_aug(&stem);
+        if let Conjugated::Some(forms) = conj {
+            for part in forms.iter_mut() {
+                *part = match part.strip_prefix(stem.as_str()) {
+                    Some(rest) => format!("{}{}", unaugmented, rest),
+                    None => self.remove_aug(part),
+                };
             }
-            _ => "ἐ",
+        }
+    }
+
+    fn initial(stem: &str) -> String {
+        stem.chars().next().map(String::from).unwrap_or_default()
+    }
+
+    // The augment of a simple verb; aor_initial is the first letter of its
+    // aorist, which tells ἠ- from εἰ-. The lexicon comes before the rules.
+    fn augment<'a>(&self, aor_initial: Option<String>, stem: &'a str) -> (String, &'a str) {
+        if let Some(augmented) = self.lexicon.augmented(stem) {
+            return (augmented.to_string(), "");
+        }
+        let ei = match (stem.chars().next(), aor_initial.as_deref()) {
+            (Some('ἐ'), Some("ἠ")) | (Some('ἑ'), Some("ἡ")) | (Some('ἑ'), None) => false,
+            (Some('ἐ'), _) => matches!(
+                self.aorist_stem,
+                Some(TenseVoiceMoodVariant::AorActIndWk(_))
+                    | Some(TenseVoiceMoodVariant::AorMidIndWk(_))
+                    | Some(TenseVoiceMoodVariant::AorPassIndWk(_))
+                    | Some(TenseVoiceMoodVariant::AorPassIndStr(_))
+            ),
+            (Some('ἑ'), _) => true,
+            _ => false,
         };
-        (aug, stem)
+        augment::apply(stem, ei)
     }
 
-    fn remove_aug(opt_pres: &Option<TenseVoiceMoodVariant>, mut stem: &str) -> String {
-        match stem {
-            stm if stm.starts_with("ἀμφε") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("ἀμφι{}", stem)
-            }
-            stm if stm.starts_with("ἀνε") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("ἀνα{}", stem)
-            }
-            stm if stm.starts_with("ἀντε") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("ἀντι{}", stem)
-            }
-            stm if stm.starts_with("ἀπε") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("ἀπο{}", stem)
-            }
-            stm if stm.starts_with("δι") => {
-                let parts: Vec<&str> = stem.splitn(2, "ε").collect();
-                format!("{}α{}", parts[0], parts[1])
-            }
-            stm if stm.starts_with("εἰσε") => {
-                stem = stem.splitn(2, "σε").collect::<Vec<&str>>()[1];
-                format!("εἰσ{}", stem)
-            }
-            stm if stm.starts_with("εἰ") => {
-                stem = stem.splitn(2, "εἰ").collect::<Vec<&str>>()[1];
-                format!("ἐ{}", stem)
-            }
-            stm if stm.starts_with("εἱ") => {
-                stem = stem.splitn(2, "εἱ").collect::<Vec<&str>>()[1];
-                format!("ἑ{}", stem)
-            }
-            stm if stm.starts_with("ἐξε") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("ἐκ{}", stem)
-            }
-            stm if stm.starts_with("ἐνεπ") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("ἐμ{}", stem)
-            }
-            stm if stm.starts_with("ἐνεβ") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("ἐμ{}", stem)
-            }
-            stm if stm.starts_with("ἐνεφ") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("ἐμ{}", stem)
+    fn remove_aug(&self, stem: &str) -> String {
+        let pres = self.pres_stem.as_ref().map(|pres| pres.to_string());
+        match preverb::split(stem, pres.as_deref()) {
+            Some(compound) => {
+                let pres_simplex = pres
+                    .as_deref()
+                    .and_then(|pres| preverb::split(pres, Some(stem)))
+                    .map(|pres| pres.simplex);
+                compound.rejoin(&self.unaugment(&compound.simplex, pres_simplex.as_deref()))
             }
-            stm if stm.starts_with("ἐνεψ") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("ἐμ{}", stem)
-            }
-            stm if stm.starts_with("ἐνεκ") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("ἐγ{}", stem)
-            }
-            stm if stm.starts_with("ἐνεγ") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("ἐγ{}", stem)
-            }
-            stm if stm.starts_with("ἐνεχ") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("ἐγ{}", stem)
-            }
-            stm if stm.starts_with("ἐνεξ") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("ἐγ{}", stem)
-            }
-            stm if stm.starts_with("ἐνει") => {
-                stem = stem.splitn(2, "ει").collect::<Vec<&str>>()[1];
-                format!("ἐνε{}", stem)
-            }
-            stm if stm.starts_with("ἐνη") => {
-                stem = stem.splitn(2, "η").collect::<Vec<&str>>()[1];
-                format!("ἐνα{}", stem)
-            }
-            stm if stm.starts_with("ἐνε") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("ἐν{}", stem)
-            }
-            stm if stm.starts_with("ἐπει") => {
-                stem = stem.splitn(2, "ει").collect::<Vec<&str>>()[1];
-                format!("ἐπε{}", stem)
-            }
-            stm if stm.starts_with("ἐπη") => {
-                stem = stem.splitn(2, "η").collect::<Vec<&str>>()[1];
-                format!("ἐπα{}", stem)
+            None => self.unaugment(stem, pres.as_deref()),
+        }
+    }
+
+    fn unaugment(&self, stem: &str, pres: Option<&str>) -> String {
+        match self.lexicon.unaugmented(stem) {
+            Some(unaugmented) => unaugmented.to_string(),
+            None => augment::remove(stem, pres),
+        }
+    }
+
+    fn inflect<S: AsRef<str>>(stem: &str, endings: &[S]) -> Conjugated {
+        let mut forms: Vec<String> = Vec::new();
+        for ending in endings.iter() {
+            let part = format!("{}{}", stem, ending.as_ref());
+            forms.push(part);
+        }
+        Conjugated::Some(forms)
+    }
+
+    // εδω-, εθη-, ἡ- shorten to εδο-, εθε-, εἱ- outside the κ singular
+    fn kap_root(&self, stm: &str) -> String {
+        let compound_iemi = matches!(
+            &self.pres_stem,
+            Some(TenseVoiceMoodVariant::PresActIndMiEps(pres)) if pres.ends_with('ι')
+        );
+        let mut root = stm.to_string();
+        match root.pop() {
+            Some('ω') => root.push('ο'),
+            Some('ἡ') => root.push_str("εἱ"),
+            Some('η') if compound_iemi => root.push_str("ει"),
+            Some('η') => root.push('ε'),
+            Some(c) => root.push(c),
+            None => {}
+        }
+        root
+    }
+
+    // The unaugmented root less its vowel, and whether that vowel was ο
+    fn kap_stem(&self, stm: &str) -> (String, bool) {
+        let root = self.kap_root(stm);
+        let mut stem = self.remove_aug(&root);
+        stem.pop();
+        (stem, root.ends_with('ο'))
+    }
+
+    // στη- → (στ, η), γνω- → (γν, ω)
+    fn root_stem(&self, stm: &str) -> (String, char) {
+        let mut stem = self.remove_aug(stm);
+        let vowel = stem.pop().unwrap_or_default();
+        (stem, vowel)
+    }
+
+    // The aorist of the bare ἱημι is all vowel, so the breathing lands on
+    // the ending: ὡ, ἑς, εἱναι
+    fn roughen(stem: &str, conj: Conjugated) -> Conjugated {
+        match conj {
+            Conjugated::Some(forms) if stem.is_empty() => Conjugated::Some(
+                forms
+                    .iter()
+                    .map(|part| preverb::breathe(part, true))
+                    .collect(),
+            ),
+            _ => conj,
+        }
+    }
+
+    // Infinitives accented on the second-last syllable, whatever precedes
+    // it: παιδεῦσαι, δοῦναι, στῆναι
+    fn penultimate(conj: Conjugated) -> Conjugated {
+        match conj {
+            Conjugated::Some(forms) => {
+                Conjugated::Some(forms.iter().map(|part| accent::penultimate(part)).collect())
             }
-            stm if stm.starts_with("ἐπε") => match opt_pres {
-                Some(pres) => {
-                    if pres.to_string().starts_with("ἐπι") {
-                        stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                        format!("ἐπι{}", stem)
+            _ => conj,
+        }
+    }
+
+    // Subjunctives and optatives whose ending contracts with the stem vowel
+    // keep the accent on the contraction: διδῶ, τιθῶμεν, διδοῖμεν, ἱσταῖο
+    fn on_contraction(stem: &str, conj: Conjugated, optative: bool) -> Conjugated {
+        let at = accent::syllable_count(stem);
+        match conj {
+            Conjugated::Some(forms) => Conjugated::Some(
+                forms
+                    .iter()
+                    .map(|part| accent::on_contraction(part, at, optative))
+                    .collect(),
+            ),
+            _ => conj,
+        }
+    }
+
+    fn roughen_declined(stem: &str, decl: Declined) -> Declined {
+        match decl {
+            Declined::Some(rows) if stem.is_empty() => Declined::Some(
+                rows.iter()
+                    .map(|row| {
+                        row.iter()
+                            .map(|part| preverb::breathe(part, true))
+                            .collect()
+                    })
+                    .collect(),
+            ),
+            _ => decl,
+        }
+    }
+
+    // κ-aorist singulars with root plurals and middles, and root aorists
+    fn mi_aorists(&self, stmtype: &TenseVoiceMoodVariant) -> (Conjugated, Conjugated) {
+        match stmtype {
+            TenseVoiceMoodVariant::AorActIndKap(stm) => {
+                let root = self.kap_root(stm);
+                let mut active_forms: Vec<String> = Vec::new();
+                for ending in ["κα", "κας", "κε"].iter() {
+                    active_forms.push(format!("{}{}", stm, ending));
+                }
+                for ending in ["μεν", "τε", "σαν", "τον", "την"].iter() {
+                    active_forms.push(format!("{}{}", root, ending));
+                }
+                // εδο-σο and εθε-σο contract to εδου and εθου
+                let second_sg = if root.ends_with('ο') || root.ends_with('ε') {
+                    let mut stem = root.clone();
+                    stem.pop();
+                    format!("{}ου", stem)
+                } else {
+                    format!("{}σο", root)
+                };
+                let mut middle_forms: Vec<String> = Vec::new();
+                for ending in ["μην", "", "το", "μεθα", "σθε", "ντο", "σθον", "σθην"].iter()
+                {
+                    if ending.is_empty() {
+                        middle_forms.push(second_sg.clone());
                     } else {
-                        stem = stem.splitn(2, "ἐ").collect::<Vec<&str>>()[1];
-                        format!("{}", stem)
+                        middle_forms.push(format!("{}{}", root, ending));
                     }
                 }
-                None => "".to_string(),
-            },
-            stm if stm.starts_with("ηὐ") => {
-                stem = stem.splitn(2, "ὐ").collect::<Vec<&str>>()[1];
-                format!("εὐ{}", stem)
-            }
-            stm if stm.starts_with("ηὑ") => {
-                stem = stem.splitn(2, "ὑ").collect::<Vec<&str>>()[1];
-                format!("εὑ{}", stem)
+                (
+                    Conjugated::Some(active_forms),
+                    Conjugated::Some(middle_forms),
+                )
             }
-            stm if stm.starts_with("ἐ") => {
-                stem = stem.splitn(2, "ἐ").collect::<Vec<&str>>()[1];
-                format!("{}", stem)
-            }
-            stm if stm.starts_with("ᾐ") => {
-                stem = stem.splitn(2, "ᾐ").collect::<Vec<&str>>()[1];
-                format!("αἰ{}", stem)
-            }
-            stm if stm.starts_with("ᾑ") => {
-                stem = stem.splitn(2, "ᾑ").collect::<Vec<&str>>()[1];
-                format!("αἱ{}", stem)
-            }
-            stm if stm.starts_with("ἠ") => match opt_pres {
-                Some(pres) => {
-                    if pres.first_char() == "ἀ" {
-                        stem = stem.splitn(2, "ἠ").collect::<Vec<&str>>()[1];
-                        format!("ἀ{}", stem)
-                    } else {
-                        stem = stem.splitn(2, "ἠ").collect::<Vec<&str>>()[1];
-                        format!("ἐ{}", stem)
-                    }
+            TenseVoiceMoodVariant::AorActIndRoot(stm) => (
+                VerbStemSet::inflect(stm, &["ν", "ς", "", "μεν", "τε", "σαν", "τον", "την"]),
+                Conjugated::None,
+            ),
+            _ => (Conjugated::None, Conjugated::None),
+        }
+    }
+
+    // Homer's iteratives in -σκ- take the present stem and no augment:
+    // φευγεσκον, καλεεσκον, ἐασκον
+    fn iteratives(&self) -> (Conjugated, Conjugated) {
+        if !matches!(self.dialect, Dialect::Homeric { .. }) {
+            return (Conjugated::None, Conjugated::None);
+        }
+        let (stem, active) = match &self.pres_stem {
+            Some(TenseVoiceMoodVariant::PresActIndReg(stm)) => (format!("{}εσκ", stm), true),
+            Some(TenseVoiceMoodVariant::PresPassIndReg(stm)) => (format!("{}εσκ", stm), false),
+            Some(TenseVoiceMoodVariant::PresActIndAlp(stm)) => (format!("{}ασκ", stm), true),
+            Some(TenseVoiceMoodVariant::PresPassIndAlp(stm)) => (format!("{}ασκ", stm), false),
+            Some(TenseVoiceMoodVariant::PresActIndEps(stm)) => (format!("{}εεσκ", stm), true),
+            Some(TenseVoiceMoodVariant::PresPassIndEps(stm)) => (format!("{}εεσκ", stm), false),
+            Some(TenseVoiceMoodVariant::PresActIndOmi(stm)) => (format!("{}οεσκ", stm), true),
+            Some(TenseVoiceMoodVariant::PresPassIndOmi(stm)) => (format!("{}οεσκ", stm), false),
+            _ => return (Conjugated::None, Conjugated::None),
+        };
+        let active = if active {
+            VerbStemSet::inflect(
+                &stem,
+                &["ον", "ες", "ε", "ομεν", "ετε", "ον", "ετον", "ετην"],
+            )
+        } else {
+            Conjugated::None
+        };
+        let middle = VerbStemSet::inflect(
+            &stem,
+            &[
+                "ομην",
+                "εο",
+                "ετο",
+                "ομεθα",
+                "εσθε",
+                "οντο",
+                "εσθον",
+                "εσθην",
+            ],
+        );
+        (active, middle)
+    }
+
+    fn pres_subjunctives(&self) -> (Conjugated, Conjugated) {
+        match &self.pres_stem {
+            Some(stmtype) => match stmtype {
+                TenseVoiceMoodVariant::PresActIndAlp(stm)
+                | TenseVoiceMoodVariant::PresActIndEps(stm)
+                | TenseVoiceMoodVariant::PresActIndOmi(stm) => {
+                    let class = self.contraction(stmtype);
+                    (
+                        VerbStemSet::inflect(
+                            "",
+                            &class.forms(
+                                stm,
+                                &["ω", "ῃς", "ῃ", "ωμεν", "ητε", "ωσι", "ητον", "ητον"],
+                            ),
+                        ),
+                        VerbStemSet::inflect(
+                            "",
+                            &class.forms(
+                                stm,
+                                &[
+                                    "ωμαι",
+                                    "ῃ",
+                                    "ηται",
+                                    "ωμεθα",
+                                    "ησθε",
+                                    "ωνται",
+                                    "ησθον",
+                                    "ησθον",
+                                ],
+                            ),
+                        ),
+                    )
+                }
+                TenseVoiceMoodVariant::PresActIndReg(stm) => (
+                    VerbStemSet::inflect(
+                        stm,
+                        &["ω", "ῃς", "ῃ", "ωμεν", "ητε", "ωσι", "ητον", "ητον"],
+                    ),
+                    VerbStemSet::inflect(
+                        stm,
+                        &[
+                            "ωμαι",
+                            "ῃ",
+                            "ηται",
+                            "ωμεθα",
+                            "ησθε",
+                            "ωνται",
+                            "ησθον",
+                            "ησθον",
+                        ],
+                    ),
+                ),
+                TenseVoiceMoodVariant::PresPassIndAlp(stm)
+                | TenseVoiceMoodVariant::PresPassIndEps(stm)
+                | TenseVoiceMoodVariant::PresPassIndOmi(stm) => {
+                    let class = self.contraction(stmtype);
+                    (
+                        Conjugated::None,
+                        VerbStemSet::inflect(
+                            "",
+                            &class.forms(
+                                stm,
+                                &[
+                                    "ωμαι",
+                                    "ῃ",
+                                    "ηται",
+                                    "ωμεθα",
+                                    "ησθε",
+                                    "ωνται",
+                                    "ησθον",
+                                    "ησθον",
+                                ],
+                            ),
+                        ),
+                    )
                 }
-                None => "".to_string(),
+                TenseVoiceMoodVariant::PresPassIndReg(stm) => (
+                    Conjugated::None,
+                    VerbStemSet::inflect(
+                        stm,
+                        &[
+                            "ωμαι",
+                            "ῃ",
+                            "ηται",
+                            "ωμεθα",
+                            "ησθε",
+                            "ωνται",
+                            "ησθον",
+                            "ησθον",
+                        ],
+                    ),
+                ),
+                TenseVoiceMoodVariant::PresActIndMiOmi(stm) => (
+                    VerbStemSet::on_contraction(
+                        stm,
+                        VerbStemSet::inflect(
+                            stm,
+                            &["ω", "ῳς", "ῳ", "ωμεν", "ωτε", "ωσι", "ωτον", "ωτον"],
+                        ),
+                        false,
+                    ),
+                    VerbStemSet::on_contraction(
+                        stm,
+                        VerbStemSet::inflect(
+                            stm,
+                            &[
+                                "ωμαι",
+                                "ῳ",
+                                "ωται",
+                                "ωμεθα",
+                                "ωσθε",
+                                "ωνται",
+                                "ωσθον",
+                                "ωσθον",
+                            ],
+                        ),
+                        false,
+                    ),
+                ),
+                TenseVoiceMoodVariant::PresActIndMiEps(stm)
+                | TenseVoiceMoodVariant::PresActIndMiAlp(stm) => (
+                    VerbStemSet::on_contraction(
+                        stm,
+                        VerbStemSet::inflect(
+                            stm,
+                            &["ω", "ῃς", "ῃ", "ωμεν", "ητε", "ωσι", "ητον", "ητον"],
+                        ),
+                        false,
+                    ),
+                    VerbStemSet::on_contraction(
+                        stm,
+                        VerbStemSet::inflect(
+                            stm,
+                            &[
+                                "ωμαι",
+                                "ῃ",
+                                "ηται",
+                                "ωμεθα",
+                                "ησθε",
+                                "ωνται",
+                                "ησθον",
+                                "ησθον",
+                            ],
+                        ),
+                        false,
+                    ),
+                ),
+                // δεικνυμι is thematic outside the indicative
+                TenseVoiceMoodVariant::PresActIndMiUps(stm) => (
+                    VerbStemSet::inflect(
+                        stm,
+                        &["υω", "υῃς", "υῃ", "υωμεν", "υητε", "υωσι", "υητον", "υητον"],
+                    ),
+                    VerbStemSet::inflect(
+                        stm,
+                        &[
+                            "υωμαι",
+                            "υῃ",
+                            "υηται",
+                            "υωμεθα",
+                            "υησθε",
+                            "υωνται",
+                            "υησθον",
+                            "υησθον",
+                        ],
+                    ),
+                ),
+                TenseVoiceMoodVariant::PresIndIrr(pp) => match irregular::find_present(pp) {
+                    Some(irr) => (VerbStemSet::inflect("", &irr.subjunctive), Conjugated::None),
+                    None => (Conjugated::None, Conjugated::None),
+                },
+                _ => (Conjugated::None, Conjugated::None),
             },
-            stm if stm.starts_with("ἡ") => match opt_pres {
-                Some(pres) => {
-                    if pres.first_char() == "ἁ" {
-                        stem = stem.splitn(2, "ἡ").collect::<Vec<&str>>()[1];
-                        format!("ἁ{}", stem)
+            None => (Conjugated::None, Conjugated::None),
+        }
+    }
+
+    fn aor_subjunctives(
+        &self,
+        opt_aor: &Option<TenseVoiceMoodVariant>,
+    ) -> (Conjugated, Conjugated) {
+        match opt_aor {
+            Some(stmtype) => match stmtype {
+                // the subjunctive has no augment, so strip it as for the future passive
+                TenseVoiceMoodVariant::AorActIndStr(stm)
+                | TenseVoiceMoodVariant::AorActIndWk(stm) => {
+                    let stem = self.remove_aug(stm);
+                    (
+                        VerbStemSet::inflect(
+                            &stem,
+                            &["ω", "ῃς", "ῃ", "ωμεν", "ητε", "ωσι", "ητον", "ητον"],
+                        ),
+                        VerbStemSet::inflect(
+                            &stem,
+                            &[
+                                "ωμαι",
+                                "ῃ",
+                                "ηται",
+                                "ωμεθα",
+                                "ησθε",
+                                "ωνται",
+                                "ησθον",
+                                "ησθον",
+                            ],
+                        ),
+                    )
+                }
+                TenseVoiceMoodVariant::AorMidIndStr(stm)
+                | TenseVoiceMoodVariant::AorMidIndWk(stm) => {
+                    let stem = self.remove_aug(stm);
+                    (
+                        Conjugated::None,
+                        VerbStemSet::inflect(
+                            &stem,
+                            &[
+                                "ωμαι",
+                                "ῃ",
+                                "ηται",
+                                "ωμεθα",
+                                "ησθε",
+                                "ωνται",
+                                "ησθον",
+                                "ησθον",
+                            ],
+                        ),
+                    )
+                }
+                TenseVoiceMoodVariant::AorPassIndWk(_)
+                | TenseVoiceMoodVariant::AorPassIndStr(_) => {
+                    // Passive Deponent aorists
+                    (self.aor_pass_subjunctive(opt_aor), Conjugated::None)
+                }
+                TenseVoiceMoodVariant::AorActIndKap(stm) => {
+                    let (stem, omicron) = self.kap_stem(stm);
+                    let (active, middle) = if omicron {
+                        (
+                            VerbStemSet::inflect(
+                                &stem,
+                                &["ω", "ῳς", "ῳ", "ωμεν", "ωτε", "ωσι", "ωτον", "ωτον"],
+                            ),
+                            VerbStemSet::inflect(
+                                &stem,
+                                &[
+                                    "ωμαι",
+                                    "ῳ",
+                                    "ωται",
+                                    "ωμεθα",
+                                    "ωσθε",
+                                    "ωνται",
+                                    "ωσθον",
+                                    "ωσθον",
+                                ],
+                            ),
+                        )
                     } else {
-                        stem = stem.splitn(2, "ἠ").collect::<Vec<&str>>()[1];
-                        format!("ἑ{}", stem)
+                        (
+                            VerbStemSet::inflect(
+                                &stem,
+                                &["ω", "ῃς", "ῃ", "ωμεν", "ητε", "ωσι", "ητον", "ητον"],
+                            ),
+                            VerbStemSet::inflect(
+                                &stem,
+                                &[
+                                    "ωμαι",
+                                    "ῃ",
+                                    "ηται",
+                                    "ωμεθα",
+                                    "ησθε",
+                                    "ωνται",
+                                    "ησθον",
+                                    "ησθον",
+                                ],
+                            ),
+                        )
+                    };
+                    (
+                        VerbStemSet::on_contraction(
+                            &stem,
+                            VerbStemSet::roughen(&stem, active),
+                            false,
+                        ),
+                        VerbStemSet::on_contraction(
+                            &stem,
+                            VerbStemSet::roughen(&stem, middle),
+                            false,
+                        ),
+                    )
+                }
+                TenseVoiceMoodVariant::AorActIndRoot(stm) => {
+                    let (stem, vowel) = self.root_stem(stm);
+                    match vowel {
+                        'η' => (
+                            VerbStemSet::on_contraction(
+                                &stem,
+                                VerbStemSet::inflect(
+                                    &stem,
+                                    &["ω", "ῃς", "ῃ", "ωμεν", "ητε", "ωσι", "ητον", "ητον"],
+                                ),
+                                false,
+                            ),
+                            Conjugated::None,
+                        ),
+                        'ω' => (
+                            VerbStemSet::on_contraction(
+                                &stem,
+                                VerbStemSet::inflect(
+                                    &stem,
+                                    &["ω", "ῳς", "ῳ", "ωμεν", "ωτε", "ωσι", "ωτον", "ωτον"],
+                                ),
+                                false,
+                            ),
+                            Conjugated::None,
+                        ),
+                        // ἐδυν is thematic here: δυω, δυῃς
+                        'υ' => (
+                            VerbStemSet::inflect(
+                                &stem,
+                                &["υω", "υῃς", "υῃ", "υωμεν", "υητε", "υωσι", "υητον", "υητον"],
+                            ),
+                            Conjugated::None,
+                        ),
+                        _ => (Conjugated::None, Conjugated::None),
                     }
                 }
-                None => "".to_string(),
+                _ => (Conjugated::None, Conjugated::None),
             },
-            stm if stm.starts_with("ἡ") => {
-                stem = stem.splitn(2, "ἡ").collect::<Vec<&str>>()[1];
-                format!("ἁ{}", stem)
-            }
-            stm if stm.starts_with("κατη") => {
-                stem = stem.splitn(2, "η").collect::<Vec<&str>>()[1];
-                format!("κατα{}", stem)
-            }
-            stm if stm.starts_with("κατε") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("κατα{}", stem)
-            }
-            stm if stm.starts_with("μετη") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("μετα{}", stem)
-            }
-            stm if stm.starts_with("μετε") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("μετα{}", stem)
-            }
-            stm if stm.starts_with("παρη") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("παρα{}", stem)
-            }
-            stm if stm.starts_with("παρε") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("παρα{}", stem)
-            }
-            stm if stm.starts_with("περιε") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("περι{}", stem)
-            }
-            stm if stm.starts_with("περιη") => {
-                stem = stem.splitn(2, "η").collect::<Vec<&str>>()[1];
-                format!("περια{}", stem)
-            }
-            stm if stm.starts_with("προσε") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("προσ{}", stem)
-            }
-            stm if stm.starts_with("πρου") => {
-                stem = stem.splitn(2, "υ").collect::<Vec<&str>>()[1];
-                format!("προ{}", stem)
-            }
-            stm if stm.starts_with("συνεγ") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("συγ{}", stem)
-            }
-            stm if stm.starts_with("συνεκ") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("συγ{}", stem)
-            }
-            stm if stm.starts_with("συνεξ") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("συγ{}", stem)
-            }
-            stm if stm.starts_with("συνεχ") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("συγ{}", stem)
+            None => (Conjugated::None, Conjugated::None),
+        }
+    }
+
+    fn aor_pass_subjunctive(&self, opt_ap: &Option<TenseVoiceMoodVariant>) -> Conjugated {
+        match opt_ap {
+            Some(TenseVoiceMoodVariant::AorPassIndWk(stm))
+            | Some(TenseVoiceMoodVariant::AorPassIndStr(stm)) => {
+                let stem = self.remove_aug(stm);
+                // contracted from -εω, so λυθῶ
+                VerbStemSet::inflect(
+                    &stem,
+                    &["ῶ", "ῇς", "ῇ", "ῶμεν", "ῆτε", "ῶσι", "ῆτον", "ῆτον"],
+                )
             }
-            stm if stm.starts_with("συνελ") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("συλ{}", stem)
+            _ => Conjugated::None,
+        }
+    }
+
+    fn pres_optatives(&self) -> (Conjugated, Conjugated) {
+        match &self.pres_stem {
+            Some(stmtype) => match stmtype {
+                TenseVoiceMoodVariant::PresActIndAlp(stm)
+                | TenseVoiceMoodVariant::PresActIndEps(stm)
+                | TenseVoiceMoodVariant::PresActIndOmi(stm) => {
+                    // contracted verbs take -οιην in the singular, but πλεοιμι
+                    let class = self.contraction(stmtype);
+                    let active = if class.thematic_optative() {
+                        [
+                            "οιμι",
+                            "οις",
+                            "οι",
+                            "οιμεν",
+                            "οιτε",
+                            "οιεν",
+                            "οιτον",
+                            "οιτην",
+                        ]
+                    } else {
+                        [
+                            "οιην",
+                            "οιης",
+                            "οιη",
+                            "οιμεν",
+                            "οιτε",
+                            "οιεν",
+                            "οιτον",
+                            "οιτην",
+                        ]
+                    };
+                    (
+                        VerbStemSet::inflect("", &class.forms(stm, &active)),
+                        VerbStemSet::inflect(
+                            "",
+                            &class.forms(
+                                stm,
+                                &[
+                                    "οιμην",
+                                    "οιο",
+                                    "οιτο",
+                                    "οιμεθα",
+                                    "οισθε",
+                                    "οιντο",
+                                    "οισθον",
+                                    "οισθην",
+                                ],
+                            ),
+                        ),
+                    )
+                }
+                TenseVoiceMoodVariant::PresActIndReg(stm) => (
+                    VerbStemSet::inflect(
+                        stm,
+                        &[
+                            "οιμι",
+                            "οις",
+                            "οι",
+                            "οιμεν",
+                            "οιτε",
+                            "οιεν",
+                            "οιτον",
+                            "οιτην",
+                        ],
+                    ),
+                    VerbStemSet::inflect(
+                        stm,
+                        &[
+                            "οιμην",
+                            "οιο",
+                            "οιτο",
+                            "οιμεθα",
+                            "οισθε",
+                            "οιντο",
+                            "οισθον",
+                            "οισθην",
+                        ],
+                    ),
+                ),
+                TenseVoiceMoodVariant::PresPassIndAlp(stm)
+                | TenseVoiceMoodVariant::PresPassIndEps(stm)
+                | TenseVoiceMoodVariant::PresPassIndOmi(stm) => {
+                    let class = self.contraction(stmtype);
+                    (
+                        Conjugated::None,
+                        VerbStemSet::inflect(
+                            "",
+                            &class.forms(
+                                stm,
+                                &[
+                                    "οιμην",
+                                    "οιο",
+                                    "οιτο",
+                                    "οιμεθα",
+                                    "οισθε",
+                                    "οιντο",
+                                    "οισθον",
+                                    "οισθην",
+                                ],
+                            ),
+                        ),
+                    )
+                }
+                TenseVoiceMoodVariant::PresPassIndReg(stm) => (
+                    Conjugated::None,
+                    VerbStemSet::inflect(
+                        stm,
+                        &[
+                            "οιμην",
+                            "οιο",
+                            "οιτο",
+                            "οιμεθα",
+                            "οισθε",
+                            "οιντο",
+                            "οισθον",
+                            "οισθην",
+                        ],
+                    ),
+                ),
+                TenseVoiceMoodVariant::PresActIndMiOmi(stm) => (
+                    VerbStemSet::on_contraction(
+                        stm,
+                        VerbStemSet::inflect(
+                            stm,
+                            &[
+                                "οιην",
+                                "οιης",
+                                "οιη",
+                                "οιμεν",
+                                "οιτε",
+                                "οιεν",
+                                "οιτον",
+                                "οιτην",
+                            ],
+                        ),
+                        true,
+                    ),
+                    VerbStemSet::on_contraction(
+                        stm,
+                        VerbStemSet::inflect(
+                            stm,
+                            &[
+                                "οιμην",
+                                "οιο",
+                                "οιτο",
+                                "οιμεθα",
+                                "οισθε",
+                                "οιντο",
+                                "οισθον",
+                                "οισθην",
+                            ],
+                        ),
+                        true,
+                    ),
+                ),
+                TenseVoiceMoodVariant::PresActIndMiEps(stm) => (
+                    VerbStemSet::on_contraction(
+                        stm,
+                        VerbStemSet::inflect(
+                            stm,
+                            &[
+                                "ειην",
+                                "ειης",
+                                "ειη",
+                                "ειμεν",
+                                "ειτε",
+                                "ειεν",
+                                "ειτον",
+                                "ειτην",
+                            ],
+                        ),
+                        true,
+                    ),
+                    VerbStemSet::on_contraction(
+                        stm,
+                        VerbStemSet::inflect(
+                            stm,
+                            &[
+                                "ειμην",
+                                "ειο",
+                                "ειτο",
+                                "ειμεθα",
+                                "εισθε",
+                                "ειντο",
+                                "εισθον",
+                                "εισθην",
+                            ],
+                        ),
+                        true,
+                    ),
+                ),
+                TenseVoiceMoodVariant::PresActIndMiAlp(stm) => (
+                    VerbStemSet::on_contraction(
+                        stm,
+                        VerbStemSet::inflect(
+                            stm,
+                            &[
+                                "αιην",
+                                "αιης",
+                                "αιη",
+                                "αιμεν",
+                                "αιτε",
+                                "αιεν",
+                                "αιτον",
+                                "αιτην",
+                            ],
+                        ),
+                        true,
+                    ),
+                    VerbStemSet::on_contraction(
+                        stm,
+                        VerbStemSet::inflect(
+                            stm,
+                            &[
+                                "αιμην",
+                                "αιο",
+                                "αιτο",
+                                "αιμεθα",
+                                "αισθε",
+                                "αιντο",
+                                "αισθον",
+                                "αισθην",
+                            ],
+                        ),
+                        true,
+                    ),
+                ),
+                TenseVoiceMoodVariant::PresActIndMiUps(stm) => (
+                    VerbStemSet::inflect(
+                        stm,
+                        &[
+                            "υοιμι",
+                            "υοις",
+                            "υοι",
+                            "υοιμεν",
+                            "υοιτε",
+                            "υοιεν",
+                            "υοιτον",
+                            "υοιτην",
+                        ],
+                    ),
+                    VerbStemSet::inflect(
+                        stm,
+                        &[
+                            "υοιμην",
+                            "υοιο",
+                            "υοιτο",
+                            "υοιμεθα",
+                            "υοισθε",
+                            "υοιντο",
+                            "υοισθον",
+                            "υοισθην",
+                        ],
+                    ),
+                ),
+                TenseVoiceMoodVariant::PresIndIrr(pp) => match irregular::find_present(pp) {
+                    Some(irr) => (VerbStemSet::inflect("", &irr.optative), Conjugated::None),
+                    None => (Conjugated::None, Conjugated::None),
+                },
+                _ => (Conjugated::None, Conjugated::None),
+            },
+            None => (Conjugated::None, Conjugated::None),
+        }
+    }
+
+    fn fut_optatives(&self, opt_fut: &Option<TenseVoiceMoodVariant>) -> (Conjugated, Conjugated) {
+        match opt_fut {
+            Some(stmtype) => match stmtype {
+                TenseVoiceMoodVariant::FutActIndEps(stm) => {
+                    let class = self.contraction(stmtype);
+                    let active = if class.thematic_optative() {
+                        [
+                            "οιμι",
+                            "οις",
+                            "οι",
+                            "οιμεν",
+                            "οιτε",
+                            "οιεν",
+                            "οιτον",
+                            "οιτην",
+                        ]
+                    } else {
+                        [
+                            "οιην",
+                            "οιης",
+                            "οιη",
+                            "οιμεν",
+                            "οιτε",
+                            "οιεν",
+                            "οιτον",
+                            "οιτην",
+                        ]
+                    };
+                    (
+                        VerbStemSet::inflect("", &class.forms(stm, &active)),
+                        VerbStemSet::inflect(
+                            "",
+                            &class.forms(
+                                stm,
+                                &[
+                                    "οιμην",
+                                    "οιο",
+                                    "οιτο",
+                                    "οιμεθα",
+                                    "οισθε",
+                                    "οιντο",
+                                    "οισθον",
+                                    "οισθην",
+                                ],
+                            ),
+                        ),
+                    )
+                }
+                TenseVoiceMoodVariant::FutActIndReg(stm) => (
+                    VerbStemSet::inflect(
+                        stm,
+                        &[
+                            "οιμι",
+                            "οις",
+                            "οι",
+                            "οιμεν",
+                            "οιτε",
+                            "οιεν",
+                            "οιτον",
+                            "οιτην",
+                        ],
+                    ),
+                    VerbStemSet::inflect(
+                        stm,
+                        &[
+                            "οιμην",
+                            "οιο",
+                            "οιτο",
+                            "οιμεθα",
+                            "οισθε",
+                            "οιντο",
+                            "οισθον",
+                            "οισθην",
+                        ],
+                    ),
+                ),
+                TenseVoiceMoodVariant::FutMidIndEps(stm) => {
+                    let class = self.contraction(stmtype);
+                    (
+                        Conjugated::None,
+                        VerbStemSet::inflect(
+                            "",
+                            &class.forms(
+                                stm,
+                                &[
+                                    "οιμην",
+                                    "οιο",
+                                    "οιτο",
+                                    "οιμεθα",
+                                    "οισθε",
+                                    "οιντο",
+                                    "οισθον",
+                                    "οισθην",
+                                ],
+                            ),
+                        ),
+                    )
+                }
+                TenseVoiceMoodVariant::FutMidIndReg(stm) => (
+                    Conjugated::None,
+                    VerbStemSet::inflect(
+                        stm,
+                        &[
+                            "οιμην",
+                            "οιο",
+                            "οιτο",
+                            "οιμεθα",
+                            "οισθε",
+                            "οιντο",
+                            "οισθον",
+                            "οισθην",
+                        ],
+                    ),
+                ),
+                // Deponent futures go in the active cell, as for the indicative
+                TenseVoiceMoodVariant::FutPassIndReg(stm) => (
+                    VerbStemSet::inflect(
+                        stm,
+                        &[
+                            "θησοιμην",
+                            "θησοιο",
+                            "θησοιτο",
+                            "θησοιμεθα",
+                            "θησοισθε",
+                            "θησοιντο",
+                            "θησοισθον",
+                            "θησοισθην",
+                        ],
+                    ),
+                    Conjugated::None,
+                ),
+                TenseVoiceMoodVariant::FutIndIrr(pp) => match irregular::find_future(pp) {
+                    Some(irr) if irr.middle => {
+                        (Conjugated::None, VerbStemSet::inflect("", &irr.optative))
+                    }
+                    Some(irr) => (VerbStemSet::inflect("", &irr.optative), Conjugated::None),
+                    None => (Conjugated::None, Conjugated::None),
+                },
+                _ => (Conjugated::None, Conjugated::None),
+            },
+            None => (Conjugated::None, Conjugated::None),
+        }
+    }
+
+    fn aor_optatives(&self, opt_aor: &Option<TenseVoiceMoodVariant>) -> (Conjugated, Conjugated) {
+        match opt_aor {
+            Some(stmtype) => match stmtype {
+                TenseVoiceMoodVariant::AorActIndStr(stm) => {
+                    let stem = self.remove_aug(stm);
+                    (
+                        VerbStemSet::inflect(
+                            &stem,
+                            &[
+                                "οιμι",
+                                "οις",
+                                "οι",
+                                "οιμεν",
+                                "οιτε",
+                                "οιεν",
+                                "οιτον",
+                                "οιτην",
+                            ],
+                        ),
+                        VerbStemSet::inflect(
+                            &stem,
+                            &[
+                                "οιμην",
+                                "οιο",
+                                "οιτο",
+                                "οιμεθα",
+                                "οισθε",
+                                "οιντο",
+                                "οισθον",
+                                "οισθην",
+                            ],
+                        ),
+                    )
+                }
+                TenseVoiceMoodVariant::AorActIndWk(stm) => {
+                    let stem = self.remove_aug(stm);
+                    (
+                        VerbStemSet::inflect(
+                            &stem,
+                            &[
+                                "αιμι",
+                                "αις",
+                                "αι",
+                                "αιμεν",
+                                "αιτε",
+                                "αιεν",
+                                "αιτον",
+                                "αιτην",
+                            ],
+                        ),
+                        VerbStemSet::inflect(
+                            &stem,
+                            &[
+                                "αιμην",
+                                "αιο",
+                                "αιτο",
+                                "αιμεθα",
+                                "αισθε",
+                                "αιντο",
+                                "αισθον",
+                                "αισθην",
+                            ],
+                        ),
+                    )
+                }
+                TenseVoiceMoodVariant::AorMidIndStr(stm) => {
+                    let stem = self.remove_aug(stm);
+                    (
+                        Conjugated::None,
+                        VerbStemSet::inflect(
+                            &stem,
+                            &[
+                                "οιμην",
+                                "οιο",
+                                "οιτο",
+                                "οιμεθα",
+                                "οισθε",
+                                "οιντο",
+                                "οισθον",
+                                "οισθην",
+                            ],
+                        ),
+                    )
+                }
+                TenseVoiceMoodVariant::AorMidIndWk(stm) => {
+                    let stem = self.remove_aug(stm);
+                    (
+                        Conjugated::None,
+                        VerbStemSet::inflect(
+                            &stem,
+                            &[
+                                "αιμην",
+                                "αιο",
+                                "αιτο",
+                                "αιμεθα",
+                                "αισθε",
+                                "αιντο",
+                                "αισθον",
+                                "αισθην",
+                            ],
+                        ),
+                    )
+                }
+                TenseVoiceMoodVariant::AorPassIndWk(_)
+                | TenseVoiceMoodVariant::AorPassIndStr(_) => {
+                    // Passive Deponent aorists
+                    (self.aor_pass_optative(opt_aor), Conjugated::None)
+                }
+                TenseVoiceMoodVariant::AorActIndKap(stm) => {
+                    let (stem, omicron) = self.kap_stem(stm);
+                    let (active, middle) = if omicron {
+                        (
+                            VerbStemSet::inflect(
+                                &stem,
+                                &[
+                                    "οιην",
+                                    "οιης",
+                                    "οιη",
+                                    "οιμεν",
+                                    "οιτε",
+                                    "οιεν",
+                                    "οιτον",
+                                    "οιτην",
+                                ],
+                            ),
+                            VerbStemSet::inflect(
+                                &stem,
+                                &[
+                                    "οιμην",
+                                    "οιο",
+                                    "οιτο",
+                                    "οιμεθα",
+                                    "οισθε",
+                                    "οιντο",
+                                    "οισθον",
+                                    "οισθην",
+                                ],
+                            ),
+                        )
+                    } else {
+                        (
+                            VerbStemSet::inflect(
+                                &stem,
+                                &[
+                                    "ειην",
+                                    "ειης",
+                                    "ειη",
+                                    "ειμεν",
+                                    "ειτε",
+                                    "ειεν",
+                                    "ειτον",
+                                    "ειτην",
+                                ],
+                            ),
+                            VerbStemSet::inflect(
+                                &stem,
+                                &[
+                                    "ειμην",
+                                    "ειο",
+                                    "ειτο",
+                                    "ειμεθα",
+                                    "εισθε",
+                                    "ειντο",
+                                    "εισθον",
+                                    "εισθην",
+                                ],
+                            ),
+                        )
+                    };
+                    (
+                        VerbStemSet::on_contraction(
+                            &stem,
+                            VerbStemSet::roughen(&stem, active),
+                            true,
+                        ),
+                        VerbStemSet::on_contraction(
+                            &stem,
+                            VerbStemSet::roughen(&stem, middle),
+                            true,
+                        ),
+                    )
+                }
+                // σταιην, γνοιην
+                TenseVoiceMoodVariant::AorActIndRoot(stm) => {
+                    let (stem, vowel) = self.root_stem(stm);
+                    match vowel {
+                        'η' => (
+                            VerbStemSet::on_contraction(
+                                &stem,
+                                VerbStemSet::inflect(
+                                    &stem,
+                                    &[
+                                        "αιην",
+                                        "αιης",
+                                        "αιη",
+                                        "αιμεν",
+                                        "αιτε",
+                                        "αιεν",
+                                        "αιτον",
+                                        "αιτην",
+                                    ],
+                                ),
+                                true,
+                            ),
+                            Conjugated::None,
+                        ),
+                        'ω' => (
+                            VerbStemSet::on_contraction(
+                                &stem,
+                                VerbStemSet::inflect(
+                                    &stem,
+                                    &[
+                                        "οιην",
+                                        "οιης",
+                                        "οιη",
+                                        "οιμεν",
+                                        "οιτε",
+                                        "οιεν",
+                                        "οιτον",
+                                        "οιτην",
+                                    ],
+                                ),
+                                true,
+                            ),
+                            Conjugated::None,
+                        ),
+                        _ => (Conjugated::None, Conjugated::None),
+                    }
+                }
+                _ => (Conjugated::None, Conjugated::None),
+            },
+            None => (Conjugated::None, Conjugated::None),
+        }
+    }
+
+    fn aor_pass_optative(&self, opt_ap: &Option<TenseVoiceMoodVariant>) -> Conjugated {
+        match opt_ap {
+            Some(TenseVoiceMoodVariant::AorPassIndWk(stm))
+            | Some(TenseVoiceMoodVariant::AorPassIndStr(stm)) => {
+                let stem = self.remove_aug(stm);
+                // the accent stays on the ει: λυθείην, λυθεῖμεν
+                VerbStemSet::inflect(
+                    &stem,
+                    &[
+                        "είην",
+                        "είης",
+                        "είη",
+                        "εῖμεν",
+                        "εῖτε",
+                        "εῖεν",
+                        "εῖτον",
+                        "είτην",
+                    ],
+                )
             }
-            stm if stm.starts_with("συνεβ") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("συμ{}", stem)
+            _ => Conjugated::None,
+        }
+    }
+
+    fn fut_pass_optative(&self, opt_ap: &Option<TenseVoiceMoodVariant>) -> Conjugated {
+        match opt_ap {
+            Some(TenseVoiceMoodVariant::AorPassIndWk(stm))
+            | Some(TenseVoiceMoodVariant::AorPassIndStr(stm)) => {
+                let stem = self.remove_aug(stm);
+                VerbStemSet::inflect(
+                    &stem,
+                    &[
+                        "ησοιμην",
+                        "ησοιο",
+                        "ησοιτο",
+                        "ησοιμεθα",
+                        "ησοισθε",
+                        "ησοιντο",
+                        "ησοισθον",
+                        "ησοισθην",
+                    ],
+                )
             }
-            stm if stm.starts_with("συνεμ") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("συμ{}", stem)
+            _ => Conjugated::None,
+        }
+    }
+
+    // Imperatives have no first person, so those slots are left blank to
+    // keep the six-column layout. The last two endings are the duals.
+    fn inflect_imperative<S: AsRef<str>>(stem: &str, endings: &[S]) -> Conjugated {
+        let mut forms: Vec<String> = Vec::new();
+        for (i, ending) in endings.iter().enumerate() {
+            if i == 0 || i == 2 {
+                forms.push(String::new());
             }
-            stm if stm.starts_with("συνεπ") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("συμ{}", stem)
+            let part = format!("{}{}", stem, ending.as_ref());
+            forms.push(part);
+        }
+        Conjugated::Some(forms)
+    }
+
+    fn pres_imperatives(&self) -> (Conjugated, Conjugated) {
+        match &self.pres_stem {
+            Some(stmtype) => match stmtype {
+                TenseVoiceMoodVariant::PresActIndAlp(stm)
+                | TenseVoiceMoodVariant::PresActIndEps(stm)
+                | TenseVoiceMoodVariant::PresActIndOmi(stm) => {
+                    let class = self.contraction(stmtype);
+                    (
+                        VerbStemSet::inflect_imperative(
+                            "",
+                            &class.forms(stm, &["ε", "ετω", "ετε", "οντων", "ετον", "ετων"]),
+                        ),
+                        VerbStemSet::inflect_imperative(
+                            "",
+                            &class.forms(stm, &["ου", "εσθω", "εσθε", "εσθων", "εσθον", "εσθων"]),
+                        ),
+                    )
+                }
+                TenseVoiceMoodVariant::PresActIndReg(stm) => (
+                    VerbStemSet::inflect_imperative(
+                        stm,
+                        &["ε", "ετω", "ετε", "οντων", "ετον", "ετων"],
+                    ),
+                    VerbStemSet::inflect_imperative(
+                        stm,
+                        &["ου", "εσθω", "εσθε", "εσθων", "εσθον", "εσθων"],
+                    ),
+                ),
+                TenseVoiceMoodVariant::PresPassIndAlp(stm)
+                | TenseVoiceMoodVariant::PresPassIndEps(stm)
+                | TenseVoiceMoodVariant::PresPassIndOmi(stm) => {
+                    let class = self.contraction(stmtype);
+                    (
+                        Conjugated::None,
+                        VerbStemSet::inflect_imperative(
+                            "",
+                            &class.forms(stm, &["ου", "εσθω", "εσθε", "εσθων", "εσθον", "εσθων"]),
+                        ),
+                    )
+                }
+                TenseVoiceMoodVariant::PresPassIndReg(stm) => (
+                    Conjugated::None,
+                    VerbStemSet::inflect_imperative(
+                        stm,
+                        &["ου", "εσθω", "εσθε", "εσθων", "εσθον", "εσθων"],
+                    ),
+                ),
+                TenseVoiceMoodVariant::PresActIndMiOmi(stm) => (
+                    VerbStemSet::inflect_imperative(
+                        stm,
+                        &["ου", "οτω", "οτε", "οντων", "οτον", "οτων"],
+                    ),
+                    VerbStemSet::inflect_imperative(
+                        stm,
+                        &["οσο", "οσθω", "οσθε", "οσθων", "οσθον", "οσθων"],
+                    ),
+                ),
+                TenseVoiceMoodVariant::PresActIndMiEps(stm) => (
+                    VerbStemSet::inflect_imperative(
+                        stm,
+                        &["ει", "ετω", "ετε", "εντων", "ετον", "ετων"],
+                    ),
+                    VerbStemSet::inflect_imperative(
+                        stm,
+                        &["εσο", "εσθω", "εσθε", "εσθων", "εσθον", "εσθων"],
+                    ),
+                ),
+                TenseVoiceMoodVariant::PresActIndMiAlp(stm) => (
+                    VerbStemSet::inflect_imperative(
+                        stm,
+                        &["η", "ατω", "ατε", "αντων", "ατον", "ατων"],
+                    ),
+                    VerbStemSet::inflect_imperative(
+                        stm,
+                        &["ασο", "ασθω", "ασθε", "ασθων", "ασθον", "ασθων"],
+                    ),
+                ),
+                TenseVoiceMoodVariant::PresActIndMiUps(stm) => (
+                    VerbStemSet::inflect_imperative(
+                        stm,
+                        &["υ", "υτω", "υτε", "υντων", "υτον", "υτων"],
+                    ),
+                    VerbStemSet::inflect_imperative(
+                        stm,
+                        &["υσο", "υσθω", "υσθε", "υσθων", "υσθον", "υσθων"],
+                    ),
+                ),
+                TenseVoiceMoodVariant::PresIndIrr(pp) => match irregular::find_present(pp) {
+                    Some(irr) => (
+                        VerbStemSet::inflect_imperative("", &irr.imperative),
+                        Conjugated::None,
+                    ),
+                    None => (Conjugated::None, Conjugated::None),
+                },
+                _ => (Conjugated::None, Conjugated::None),
+            },
+            None => (Conjugated::None, Conjugated::None),
+        }
+    }
+
+    fn aor_imperatives(&self, opt_aor: &Option<TenseVoiceMoodVariant>) -> (Conjugated, Conjugated) {
+        match opt_aor {
+            Some(stmtype) => match stmtype {
+                // The strong aorist middle's 2nd singular keeps a circumflex
+                // on its ending, as the infinitives keep theirs: ἐλθοῦ,
+                // ἐλθεῖν, ἐλθέσθαι
+                TenseVoiceMoodVariant::AorActIndStr(stm) => {
+                    let stem = self.remove_aug(stm);
+                    (
+                        VerbStemSet::inflect_imperative(
+                            &stem,
+                            &["ε", "ετω", "ετε", "οντων", "ετον", "ετων"],
+                        ),
+                        VerbStemSet::inflect_imperative(
+                            &stem,
+                            &["οῦ", "εσθω", "εσθε", "εσθων", "εσθον", "εσθων"],
+                        ),
+                    )
+                }
+                TenseVoiceMoodVariant::AorActIndWk(stm) => {
+                    let stem = self.remove_aug(stm);
+                    (
+                        VerbStemSet::inflect_imperative(
+                            &stem,
+                            &["ον", "ατω", "ατε", "αντων", "ατον", "ατων"],
+                        ),
+                        VerbStemSet::inflect_imperative(
+                            &stem,
+                            &["αι", "ασθω", "ασθε", "ασθων", "ασθον", "ασθων"],
+                        ),
+                    )
+                }
+                TenseVoiceMoodVariant::AorMidIndStr(stm) => {
+                    let stem = self.remove_aug(stm);
+                    (
+                        Conjugated::None,
+                        VerbStemSet::inflect_imperative(
+                            &stem,
+                            &["οῦ", "εσθω", "εσθε", "εσθων", "εσθον", "εσθων"],
+                        ),
+                    )
+                }
+                TenseVoiceMoodVariant::AorMidIndWk(stm) => {
+                    let stem = self.remove_aug(stm);
+                    (
+                        Conjugated::None,
+                        VerbStemSet::inflect_imperative(
+                            &stem,
+                            &["αι", "ασθω", "ασθε", "ασθων", "ασθον", "ασθων"],
+                        ),
+                    )
+                }
+                TenseVoiceMoodVariant::AorPassIndWk(_)
+                | TenseVoiceMoodVariant::AorPassIndStr(_) => {
+                    // Passive Deponent aorists
+                    (self.aor_pass_imperative(opt_aor), Conjugated::None)
+                }
+                TenseVoiceMoodVariant::AorActIndKap(stm) => {
+                    let (stem, omicron) = self.kap_stem(stm);
+                    let (active, middle) = if omicron {
+                        (
+                            VerbStemSet::inflect_imperative(
+                                &stem,
+                                &["ος", "οτω", "οτε", "οντων", "οτον", "οτων"],
+                            ),
+                            VerbStemSet::inflect_imperative(
+                                &stem,
+                                &["ου", "οσθω", "οσθε", "οσθων", "οσθον", "οσθων"],
+                            ),
+                        )
+                    } else {
+                        (
+                            VerbStemSet::inflect_imperative(
+                                &stem,
+                                &["ες", "ετω", "ετε", "εντων", "ετον", "ετων"],
+                            ),
+                            VerbStemSet::inflect_imperative(
+                                &stem,
+                                &["ου", "εσθω", "εσθε", "εσθων", "εσθον", "εσθων"],
+                            ),
+                        )
+                    };
+                    (
+                        VerbStemSet::roughen(&stem, active),
+                        VerbStemSet::roughen(&stem, middle),
+                    )
+                }
+                TenseVoiceMoodVariant::AorActIndRoot(stm) => {
+                    let (stem, vowel) = self.root_stem(stm);
+                    match vowel {
+                        'η' => (
+                            VerbStemSet::inflect_imperative(
+                                &stem,
+                                &["ηθι", "ητω", "ητε", "αντων", "ητον", "ητων"],
+                            ),
+                            Conjugated::None,
+                        ),
+                        'ω' => (
+                            VerbStemSet::inflect_imperative(
+                                &stem,
+                                &["ωθι", "ωτω", "ωτε", "οντων", "ωτον", "ωτων"],
+                            ),
+                            Conjugated::None,
+                        ),
+                        'υ' => (
+                            VerbStemSet::inflect_imperative(
+                                &stem,
+                                &["υθι", "υτω", "υτε", "υντων", "υτον", "υτων"],
+                            ),
+                            Conjugated::None,
+                        ),
+                        _ => (Conjugated::None, Conjugated::None),
+                    }
+                }
+                _ => (Conjugated::None, Conjugated::None),
+            },
+            None => (Conjugated::None, Conjugated::None),
+        }
+    }
+
+    fn aor_pass_imperative(&self, opt_ap: &Option<TenseVoiceMoodVariant>) -> Conjugated {
+        match opt_ap {
+            // -θι becomes -τι after the aspirate of the -θη- suffix
+            Some(TenseVoiceMoodVariant::AorPassIndWk(stm)) => {
+                let stem = self.remove_aug(stm);
+                VerbStemSet::inflect_imperative(
+                    &stem,
+                    &["ητι", "ητω", "ητε", "εντων", "ητον", "ητων"],
+                )
             }
-            stm if stm.starts_with("συνεφ") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("συμ{}", stem)
+            Some(TenseVoiceMoodVariant::AorPassIndStr(stm)) => {
+                let stem = self.remove_aug(stm);
+                VerbStemSet::inflect_imperative(
+                    &stem,
+                    &["ηθι", "ητω", "ητε", "εντων", "ητον", "ητων"],
+                )
             }
-            stm if stm.starts_with("συνερ") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("συρ{}", stem)
+            _ => Conjugated::None,
+        }
+    }
+
+    fn pres_infinitives(&self) -> (Conjugated, Conjugated) {
+        match &self.pres_stem {
+            Some(stmtype) => match stmtype {
+                TenseVoiceMoodVariant::PresActIndAlp(stm)
+                | TenseVoiceMoodVariant::PresActIndEps(stm)
+                | TenseVoiceMoodVariant::PresActIndOmi(stm) => {
+                    let class = self.contraction(stmtype);
+                    (
+                        VerbStemSet::inflect("", &class.forms(stm, &["εν"])),
+                        VerbStemSet::inflect("", &class.forms(stm, &["εσθαι"])),
+                    )
+                }
+                TenseVoiceMoodVariant::PresActIndReg(stm) => (
+                    VerbStemSet::inflect(stm, &["ειν"]),
+                    VerbStemSet::inflect(stm, &["εσθαι"]),
+                ),
+                TenseVoiceMoodVariant::PresPassIndAlp(stm)
+                | TenseVoiceMoodVariant::PresPassIndEps(stm)
+                | TenseVoiceMoodVariant::PresPassIndOmi(stm) => {
+                    let class = self.contraction(stmtype);
+                    (
+                        Conjugated::None,
+                        VerbStemSet::inflect("", &class.forms(stm, &["εσθαι"])),
+                    )
+                }
+                TenseVoiceMoodVariant::PresPassIndReg(stm) => {
+                    (Conjugated::None, VerbStemSet::inflect(stm, &["εσθαι"]))
+                }
+                TenseVoiceMoodVariant::PresActIndMiOmi(stm) => (
+                    VerbStemSet::inflect(stm, &["όναι"]),
+                    VerbStemSet::inflect(stm, &["οσθαι"]),
+                ),
+                TenseVoiceMoodVariant::PresActIndMiEps(stm) => (
+                    VerbStemSet::inflect(stm, &["έναι"]),
+                    VerbStemSet::inflect(stm, &["εσθαι"]),
+                ),
+                TenseVoiceMoodVariant::PresActIndMiAlp(stm) => (
+                    VerbStemSet::inflect(stm, &["άναι"]),
+                    VerbStemSet::inflect(stm, &["ασθαι"]),
+                ),
+                TenseVoiceMoodVariant::PresActIndMiUps(stm) => (
+                    VerbStemSet::inflect(stm, &["ύναι"]),
+                    VerbStemSet::inflect(stm, &["υσθαι"]),
+                ),
+                TenseVoiceMoodVariant::PresIndIrr(pp) => match irregular::find_present(pp) {
+                    Some(irr) => (
+                        VerbStemSet::inflect("", &[irr.infinitive]),
+                        Conjugated::None,
+                    ),
+                    None => (Conjugated::None, Conjugated::None),
+                },
+                _ => (Conjugated::None, Conjugated::None),
+            },
+            None => (Conjugated::None, Conjugated::None),
+        }
+    }
+
+    fn fut_infinitives(&self, opt_fut: &Option<TenseVoiceMoodVariant>) -> (Conjugated, Conjugated) {
+        match opt_fut {
+            Some(stmtype) => match stmtype {
+                TenseVoiceMoodVariant::FutActIndEps(stm) => {
+                    let class = self.contraction(stmtype);
+                    (
+                        VerbStemSet::inflect("", &class.forms(stm, &["εν"])),
+                        VerbStemSet::inflect("", &class.forms(stm, &["εσθαι"])),
+                    )
+                }
+                TenseVoiceMoodVariant::FutActIndReg(stm) => (
+                    VerbStemSet::inflect(stm, &["ειν"]),
+                    VerbStemSet::inflect(stm, &["εσθαι"]),
+                ),
+                TenseVoiceMoodVariant::FutMidIndEps(stm) => {
+                    let class = self.contraction(stmtype);
+                    (
+                        Conjugated::None,
+                        VerbStemSet::inflect("", &class.forms(stm, &["εσθαι"])),
+                    )
+                }
+                TenseVoiceMoodVariant::FutMidIndReg(stm) => {
+                    (Conjugated::None, VerbStemSet::inflect(stm, &["εσθαι"]))
+                }
+                // Deponent futures go in the active cell, as for the indicative
+                TenseVoiceMoodVariant::FutPassIndReg(stm) => {
+                    (VerbStemSet::inflect(stm, &["θησεσθαι"]), Conjugated::None)
+                }
+                TenseVoiceMoodVariant::FutIndIrr(pp) => match irregular::find_future(pp) {
+                    Some(irr) if irr.middle => (
+                        Conjugated::None,
+                        VerbStemSet::inflect("", &[irr.infinitive]),
+                    ),
+                    Some(irr) => (
+                        VerbStemSet::inflect("", &[irr.infinitive]),
+                        Conjugated::None,
+                    ),
+                    None => (Conjugated::None, Conjugated::None),
+                },
+                _ => (Conjugated::None, Conjugated::None),
+            },
+            None => (Conjugated::None, Conjugated::None),
+        }
+    }
+
+    fn aor_infinitives(&self, opt_aor: &Option<TenseVoiceMoodVariant>) -> (Conjugated, Conjugated) {
+        match opt_aor {
+            Some(stmtype) => match stmtype {
+                TenseVoiceMoodVariant::AorActIndStr(stm) => {
+                    let stem = self.remove_aug(stm);
+                    (
+                        VerbStemSet::inflect(&stem, &["εῖν"]),
+                        VerbStemSet::inflect(&stem, &["έσθαι"]),
+                    )
+                }
+                TenseVoiceMoodVariant::AorActIndWk(stm) => {
+                    let stem = self.remove_aug(stm);
+                    (
+                        VerbStemSet::penultimate(VerbStemSet::inflect(&stem, &["αι"])),
+                        VerbStemSet::inflect(&stem, &["ασθαι"]),
+                    )
+                }
+                TenseVoiceMoodVariant::AorMidIndStr(stm) => {
+                    let stem = self.remove_aug(stm);
+                    (Conjugated::None, VerbStemSet::inflect(&stem, &["έσθαι"]))
+                }
+                TenseVoiceMoodVariant::AorMidIndWk(stm) => {
+                    let stem = self.remove_aug(stm);
+                    (Conjugated::None, VerbStemSet::inflect(&stem, &["ασθαι"]))
+                }
+                TenseVoiceMoodVariant::AorPassIndWk(_)
+                | TenseVoiceMoodVariant::AorPassIndStr(_) => {
+                    // Passive Deponent aorists
+                    (self.aor_pass_infinitive(opt_aor), Conjugated::None)
+                }
+                TenseVoiceMoodVariant::AorActIndKap(stm) => {
+                    let (stem, omicron) = self.kap_stem(stm);
+                    let (active, middle) = if omicron {
+                        (
+                            VerbStemSet::inflect(&stem, &["ουναι"]),
+                            VerbStemSet::inflect(&stem, &["οσθαι"]),
+                        )
+                    } else {
+                        (
+                            VerbStemSet::inflect(&stem, &["ειναι"]),
+                            VerbStemSet::inflect(&stem, &["εσθαι"]),
+                        )
+                    };
+                    (
+                        VerbStemSet::penultimate(VerbStemSet::roughen(&stem, active)),
+                        VerbStemSet::penultimate(VerbStemSet::roughen(&stem, middle)),
+                    )
+                }
+                TenseVoiceMoodVariant::AorActIndRoot(stm) => {
+                    let stem = self.remove_aug(stm);
+                    (
+                        VerbStemSet::penultimate(VerbStemSet::inflect(&stem, &["ναι"])),
+                        Conjugated::None,
+                    )
+                }
+                _ => (Conjugated::None, Conjugated::None),
+            },
+            None => (Conjugated::None, Conjugated::None),
+        }
+    }
+
+    fn aor_pass_infinitive(&self, opt_ap: &Option<TenseVoiceMoodVariant>) -> Conjugated {
+        match opt_ap {
+            Some(TenseVoiceMoodVariant::AorPassIndWk(stm))
+            | Some(TenseVoiceMoodVariant::AorPassIndStr(stm)) => {
+                let stem = self.remove_aug(stm);
+                VerbStemSet::inflect(&stem, &["ῆναι"])
             }
-            stm if stm.starts_with("συνεσ") => {
-                stem = stem.splitn(2, "ε").collect::<Vec<&str>>()[1];
-                format!("συσ{}", stem)
+            _ => Conjugated::None,
+        }
+    }
+
+    fn fut_pass_infinitive(&self, opt_ap: &Option<TenseVoiceMoodVariant>) -> Conjugated {
+        match opt_ap {
+            Some(TenseVoiceMoodVariant::AorPassIndWk(stm))
+            | Some(TenseVoiceMoodVariant::AorPassIndStr(stm)) => {
+                let stem = self.remove_aug(stm);
+                VerbStemSet::inflect(&stem, &["ησεσθαι"])
             }
-            stm if stm.starts_with("ᾠ") => {
-                stem = stem.splitn(2, "ᾠ").collect::<Vec<&str>>()[1];
-                format!("οἰ{}", stem)
+            _ => Conjugated::None,
+        }
+    }
+
+    fn pres_participles(&self) -> (Declined, Declined) {
+        match &self.pres_stem {
+            Some(stmtype) => match stmtype {
+                TenseVoiceMoodVariant::PresActIndAlp(stm)
+                | TenseVoiceMoodVariant::PresActIndEps(stm)
+                | TenseVoiceMoodVariant::PresActIndOmi(stm) => {
+                    let class = self.contraction(stmtype);
+                    (
+                        class.decline(stm, &THEMATIC),
+                        decline(&format!("{}{}", stm, class.ending("ομεν")), &MIDDLE),
+                    )
+                }
+                TenseVoiceMoodVariant::PresActIndReg(stm) => (
+                    decline(stm, &THEMATIC),
+                    decline(&format!("{}ομεν", stm), &MIDDLE),
+                ),
+                TenseVoiceMoodVariant::PresPassIndAlp(stm)
+                | TenseVoiceMoodVariant::PresPassIndEps(stm)
+                | TenseVoiceMoodVariant::PresPassIndOmi(stm) => {
+                    let class = self.contraction(stmtype);
+                    (
+                        Declined::None,
+                        decline(&format!("{}{}", stm, class.ending("ομεν")), &MIDDLE),
+                    )
+                }
+                TenseVoiceMoodVariant::PresPassIndReg(stm) => {
+                    (Declined::None, decline(&format!("{}ομεν", stm), &MIDDLE))
+                }
+                TenseVoiceMoodVariant::PresActIndMiOmi(stm) => (
+                    decline(stm, &OMICRON_ROOT),
+                    decline(&format!("{}ομεν", stm), &MIDDLE),
+                ),
+                TenseVoiceMoodVariant::PresActIndMiEps(stm) => (
+                    decline(stm, &AORIST_PASSIVE),
+                    decline(&format!("{}εμεν", stm), &MIDDLE),
+                ),
+                TenseVoiceMoodVariant::PresActIndMiAlp(stm) => (
+                    decline(stm, &WEAK_AORIST),
+                    decline(&format!("{}αμεν", stm), &MIDDLE),
+                ),
+                TenseVoiceMoodVariant::PresActIndMiUps(stm) => (
+                    decline(stm, &UPSILON_ROOT),
+                    decline(&format!("{}υμεν", stm), &MIDDLE),
+                ),
+                TenseVoiceMoodVariant::PresIndIrr(pp) => match irregular::find_present(pp) {
+                    Some(irr) => (decline(irr.participle.0, irr.participle.1), Declined::None),
+                    None => (Declined::None, Declined::None),
+                },
+                _ => (Declined::None, Declined::None),
+            },
+            None => (Declined::None, Declined::None),
+        }
+    }
+
+    fn fut_participles(&self, opt_fut: &Option<TenseVoiceMoodVariant>) -> (Declined, Declined) {
+        match opt_fut {
+            Some(stmtype) => match stmtype {
+                TenseVoiceMoodVariant::FutActIndEps(stm) => {
+                    let class = self.contraction(stmtype);
+                    (
+                        class.decline(stm, &THEMATIC),
+                        decline(&format!("{}{}", stm, class.ending("ομεν")), &MIDDLE),
+                    )
+                }
+                TenseVoiceMoodVariant::FutActIndReg(stm) => (
+                    decline(stm, &THEMATIC),
+                    decline(&format!("{}ομεν", stm), &MIDDLE),
+                ),
+                TenseVoiceMoodVariant::FutMidIndEps(stm) => {
+                    let class = self.contraction(stmtype);
+                    (
+                        Declined::None,
+                        decline(&format!("{}{}", stm, class.ending("ομεν")), &MIDDLE),
+                    )
+                }
+                TenseVoiceMoodVariant::FutMidIndReg(stm) => {
+                    (Declined::None, decline(&format!("{}ομεν", stm), &MIDDLE))
+                }
+                // Deponent futures go in the active cell, as for the indicative
+                TenseVoiceMoodVariant::FutPassIndReg(stm) => {
+                    (decline(&format!("{}θησομεν", stm), &MIDDLE), Declined::None)
+                }
+                TenseVoiceMoodVariant::FutIndIrr(pp) => match irregular::find_future(pp) {
+                    Some(irr) if irr.middle => {
+                        (Declined::None, decline(irr.participle.0, irr.participle.1))
+                    }
+                    Some(irr) => (decline(irr.participle.0, irr.participle.1), Declined::None),
+                    None => (Declined::None, Declined::None),
+                },
+                _ => (Declined::None, Declined::None),
+            },
+            None => (Declined::None, Declined::None),
+        }
+    }
+
+    fn aor_participles(&self, opt_aor: &Option<TenseVoiceMoodVariant>) -> (Declined, Declined) {
+        match opt_aor {
+            Some(stmtype) => match stmtype {
+                TenseVoiceMoodVariant::AorActIndStr(stm) => {
+                    let stem = self.remove_aug(stm);
+                    (
+                        decline(&stem, &THEMATIC),
+                        decline(&format!("{}ομεν", stem), &MIDDLE),
+                    )
+                }
+                TenseVoiceMoodVariant::AorActIndWk(stm) => {
+                    let stem = self.remove_aug(stm);
+                    (
+                        decline(&stem, &WEAK_AORIST),
+                        decline(&format!("{}αμεν", stem), &MIDDLE),
+                    )
+                }
+                TenseVoiceMoodVariant::AorMidIndStr(stm) => {
+                    let stem = self.remove_aug(stm);
+                    (Declined::None, decline(&format!("{}ομεν", stem), &MIDDLE))
+                }
+                TenseVoiceMoodVariant::AorMidIndWk(stm) => {
+                    let stem = self.remove_aug(stm);
+                    (Declined::None, decline(&format!("{}αμεν", stem), &MIDDLE))
+                }
+                TenseVoiceMoodVariant::AorPassIndWk(_)
+                | TenseVoiceMoodVariant::AorPassIndStr(_) => {
+                    // Passive Deponent aorists
+                    (self.aor_pass_participle(opt_aor), Declined::None)
+                }
+                TenseVoiceMoodVariant::AorActIndKap(stm) => {
+                    let (stem, omicron) = self.kap_stem(stm);
+                    let (active, middle) = if omicron {
+                        (
+                            decline(&stem, &OMICRON_ROOT),
+                            decline(&format!("{}ομεν", stem), &MIDDLE),
+                        )
+                    } else {
+                        (
+                            decline(&stem, &AORIST_PASSIVE),
+                            decline(&format!("{}εμεν", stem), &MIDDLE),
+                        )
+                    };
+                    (
+                        VerbStemSet::roughen_declined(&stem, active),
+                        VerbStemSet::roughen_declined(&stem, middle),
+                    )
+                }
+                // στας, γνους, δυς
+                TenseVoiceMoodVariant::AorActIndRoot(stm) => {
+                    let (stem, vowel) = self.root_stem(stm);
+                    match vowel {
+                        'η' => (decline(&stem, &WEAK_AORIST), Declined::None),
+                        'ω' => (decline(&stem, &OMICRON_ROOT), Declined::None),
+                        'υ' => (decline(&stem, &UPSILON_ROOT), Declined::None),
+                        _ => (Declined::None, Declined::None),
+                    }
+                }
+                _ => (Declined::None, Declined::None),
+            },
+            None => (Declined::None, Declined::None),
+        }
+    }
+
+    fn aor_pass_participle(&self, opt_ap: &Option<TenseVoiceMoodVariant>) -> Declined {
+        match opt_ap {
+            Some(TenseVoiceMoodVariant::AorPassIndWk(stm))
+            | Some(TenseVoiceMoodVariant::AorPassIndStr(stm)) => {
+                let stem = self.remove_aug(stm);
+                decline(&stem, &AORIST_PASSIVE)
             }
-            stm if stm.starts_with("ᾡ") => {
-                stem = stem.splitn(2, "ᾡ").collect::<Vec<&str>>()[1];
-                format!("οἱ{}", stem)
+            _ => Declined::None,
+        }
+    }
+
+    // The verbal adjectives take the aorist passive stem without its θ, and
+    // an aspirate before the θ goes back to the plain stop: πεμφθ-, πεμπτεος;
+    // πραχθ-, πρακτεος. A strong aorist passive's φ and χ do the same.
+    fn verbal_adjectives(&self, opt_ap: &Option<TenseVoiceMoodVariant>) -> (Declined, Declined) {
+        let mut stem = match opt_ap {
+            Some(TenseVoiceMoodVariant::AorPassIndWk(stm)) => {
+                let mut stem = self.remove_aug(stm);
+                stem.pop();
+                stem
             }
-            stm if stm.starts_with("ὠ") => {
-                stem = stem.splitn(2, "ὠ").collect::<Vec<&str>>()[1];
-                format!("ὀ{}", stem)
+            Some(TenseVoiceMoodVariant::AorPassIndStr(stm)) => self.remove_aug(stm),
+            _ => return (Declined::None, Declined::None),
+        };
+        match stem.pop() {
+            Some('φ') | Some('β') => stem.push('π'),
+            Some('χ') | Some('γ') => stem.push('κ'),
+            Some(c) => stem.push(c),
+            None => return (Declined::None, Declined::None),
+        }
+        // ἐτυθην and ἐταφην lost the aspirate of θυ- and θαπτ- to the one
+        // that follows, and θυτεος and θαπτεος have it back
+        let pres_initial = self.pres_stem.as_ref().map(|pres| pres.first_char());
+        let aspirate = match (stem.chars().next(), pres_initial.as_deref()) {
+            (Some('τ'), Some("θ")) => "θ",
+            (Some('π'), Some("φ")) => "φ",
+            (Some('κ'), Some("χ")) => "χ",
+            _ => "",
+        };
+        if !aspirate.is_empty() {
+            // the plain stops are all the same width as their aspirates
+            stem.replace_range(..aspirate.len(), aspirate);
+        }
+        (
+            decline(&format!("{}τε", stem), &ALPHA_PURE),
+            decline(&format!("{}τ", stem), &MIDDLE),
+        )
+    }
+
+    fn fut_pass_participle(&self, opt_ap: &Option<TenseVoiceMoodVariant>) -> Declined {
+        match opt_ap {
+            Some(TenseVoiceMoodVariant::AorPassIndWk(stm))
+            | Some(TenseVoiceMoodVariant::AorPassIndStr(stm)) => {
+                let stem = self.remove_aug(stm);
+                decline(&format!("{}ησομεν", stem), &MIDDLE)
             }
-            stm if stm.starts_with("ὡ") => {
-                stem = stem.splitn(2, "ὡ").collect::<Vec<&str>>()[1];
-                format!("ὁ{}", stem)
+            _ => Declined::None,
+        }
+    }
+
+    // λελυκ-, πεφευγ-, τεθυκ-: a consonant repeated (or deaspirated) with ε
+    fn is_reduplicated(stem: &str) -> bool {
+        let chars: Vec<char> = stem.chars().collect();
+        if chars.len() < 3 || chars[1] != 'ε' {
+            return false;
+        }
+        match (chars[0], chars[2]) {
+            ('τ', 'θ') | ('π', 'φ') | ('κ', 'χ') => true,
+            (first, third) => first == third && !"αεηιουω".contains(first),
+        }
+    }
+
+    // The pluperfect only takes the syllabic augment when the perfect is
+    // reduplicated; perfects which begin with a vowel (ἠγγελκα, ἐσταλκα) are
+    // left as they are.
+    fn plup_aug_and_stem<'a>(&self, stem: &'a str) -> (String, &'a str) {
+        if !self.augmented() {
+            return (String::new(), stem);
+        }
+        if VerbStemSet::is_reduplicated(stem) {
+            return ("ἐ".to_string(), stem);
+        }
+        let (aug, rest) = self.aug_and_stem(stem);
+        if VerbStemSet::is_reduplicated(rest) {
+            (aug, rest)
+        } else {
+            (String::new(), stem)
+        }
+    }
+
+    fn perf_actives(&self, opt_perf: &Option<TenseVoiceMoodVariant>) -> (Conjugated, Conjugated) {
+        match opt_perf {
+            // κ-perfects and strong perfects share their endings
+            Some(TenseVoiceMoodVariant::PerfActIndKap(stm))
+            | Some(TenseVoiceMoodVariant::PerfActIndStr(stm)) => {
+                let (aug, stem) = self.plup_aug_and_stem(stm);
+                (
+                    VerbStemSet::inflect(
+                        stm,
+                        &["α", "ας", "ε", "αμεν", "ατε", "ασι", "ατον", "ατον"],
+                    ),
+                    VerbStemSet::inflect(
+                        &format!("{}{}", aug, stem),
+                        &["η", "ης", "ει", "εμεν", "ετε", "εσαν", "ετον", "ετην"],
+                    ),
+                )
             }
-            stm if stm.starts_with("ὑπ") => {
-                let parts: Vec<&str> = stem.splitn(2, "ε").collect();
-                format!("{}ο{}", parts[0], parts[1])
+            Some(stmtype) => {
+                eprintln!("Unable to conjugate perfect active for {}", &stmtype);
+                (Conjugated::None, Conjugated::None)
             }
-            stm if stm.contains("ε") => {
-                let parts: Vec<&str> = stem.splitn(2, "ε").collect();
-                format!("{}{}", parts[0], parts[1])
+            None => (Conjugated::None, Conjugated::None),
+        }
+    }
+
+    // Consonant stems have no simple 3rd plural, so it is made from the
+    // participle with εἰσί or ἦσαν. Both words come accented, the participle
+    // on its -μέν- as always: γεγραμμένοι εἰσί.
+    fn perf_middles(&self, opt_perf: &Option<TenseVoiceMoodVariant>) -> (Conjugated, Conjugated) {
+        let (stm, perf_endings, plup_endings) = match opt_perf {
+            Some(TenseVoiceMoodVariant::PerfMidIndVow(stm)) => (
+                stm,
+                ["μαι", "σαι", "ται", "μεθα", "σθε", "νται", "σθον", "σθον"],
+                ["μην", "σο", "το", "μεθα", "σθε", "ντο", "σθον", "σθην"],
+            ),
+            Some(TenseVoiceMoodVariant::PerfMidIndLab(stm)) => (
+                stm,
+                [
+                    "μμαι",
+                    "ψαι",
+                    "πται",
+                    "μμεθα",
+                    "φθε",
+                    "μμένοι εἰσί",
+                    "φθον",
+                    "φθον",
+                ],
+                [
+                    "μμην",
+                    "ψο",
+                    "πτο",
+                    "μμεθα",
+                    "φθε",
+                    "μμένοι ἦσαν",
+                    "φθον",
+                    "φθην",
+                ],
+            ),
+            Some(TenseVoiceMoodVariant::PerfMidIndVel(stm)) => (
+                stm,
+                [
+                    "γμαι",
+                    "ξαι",
+                    "κται",
+                    "γμεθα",
+                    "χθε",
+                    "γμένοι εἰσί",
+                    "χθον",
+                    "χθον",
+                ],
+                [
+                    "γμην",
+                    "ξο",
+                    "κτο",
+                    "γμεθα",
+                    "χθε",
+                    "γμένοι ἦσαν",
+                    "χθον",
+                    "χθην",
+                ],
+            ),
+            Some(TenseVoiceMoodVariant::PerfMidIndDen(stm)) => (
+                stm,
+                [
+                    "σμαι",
+                    "σαι",
+                    "σται",
+                    "σμεθα",
+                    "σθε",
+                    "σμένοι εἰσί",
+                    "σθον",
+                    "σθον",
+                ],
+                [
+                    "σμην",
+                    "σο",
+                    "στο",
+                    "σμεθα",
+                    "σθε",
+                    "σμένοι ἦσαν",
+                    "σθον",
+                    "σθην",
+                ],
+            ),
+            Some(TenseVoiceMoodVariant::PerfMidIndLiq(stm)) => (
+                stm,
+                [
+                    "μαι",
+                    "σαι",
+                    "ται",
+                    "μεθα",
+                    "θε",
+                    "μένοι εἰσί",
+                    "θον",
+                    "θον",
+                ],
+                ["μην", "σο", "το", "μεθα", "θε", "μένοι ἦσαν", "θον", "θην"],
+            ),
+            Some(stmtype) => {
+                eprintln!("Unable to conjugate perfect middle for {}", &stmtype);
+                return (Conjugated::None, Conjugated::None);
             }
-            _ => format!("{}", stem),
+            None => return (Conjugated::None, Conjugated::None),
+        };
+        let (aug, stem) = self.plup_aug_and_stem(stm);
+        let mut plup_forms: Vec<String> = Vec::new();
+        for (i, ending) in plup_endings.iter().enumerate() {
+            // the periphrastic form keeps the unaugmented participle
+            let part = if i == 5 && ending.contains(' ') {
+                format!("{}{}", stm, ending)
+            } else {
+                format!("{}{}{}", aug, stem, ending)
+            };
+            plup_forms.push(part);
         }
+        (
+            VerbStemSet::inflect(stm, &perf_endings),
+            Conjugated::Some(plup_forms),
+        )
     }
 
-    pub fn conjugate(&self) -> Verb {
+    pub fn conjugate(&self, nu: MovableNu) -> Verb {
         let pai: Conjugated;
         let ppi: Conjugated;
         let iai: Conjugated;
         let ipi: Conjugated;
         match &self.pres_stem {
-            Some(stmtype) => match &stmtype {
-                &TenseVoiceMoodVariant::PresActIndAlp(stm) => {
-                    let mut active_forms: Vec<String> = Vec::new();
-                    for ending in ["ω", "ᾳς", "ᾳ", "ωμεν", "ατε", "ωσι"].iter() {
-                        let part = format!("{}{}", stm, ending);
-                        active_forms.push(part);
-                    }
-                    pai = Conjugated::Some(active_forms);
-                    let mut passive_forms: Vec<String> = Vec::new();
-                    for ending in ["ωμαι", "ῳ", "αται", "ωμεθα", "ασθε", "ωνται"].iter()
-                    {
-                        let part = format!("{}{}", stm, ending);
-                        passive_forms.push(part);
-                    }
-                    ppi = Conjugated::Some(passive_forms);
-                    // imperfects
-                    let (aug, stem) = VerbStemSet::aug_and_stem(&self.aorist_stem, &stm);
-                    let mut impf_active_forms: Vec<String> = Vec::new();
-                    for ending in ["ων", "ας", "α", "ωμεν", "ατε", "ων"].iter() {
-                        let part = format!("{}{}{}", aug, stem, ending);
-                        impf_active_forms.push(part);
-                    }
-                    iai = Conjugated::Some(impf_active_forms);
-                    let mut impf_passive_forms: Vec<String> = Vec::new();
-                    for ending in ["ωμην", "ω", "ατο", "ωμεθα", "ασθε", "ωντο"].iter()
-                    {
-                        let part = format!("{}{}{}", aug, stem, ending);
-                        impf_passive_forms.push(part);
-                    }
-                    ipi = Conjugated::Some(impf_passive_forms);
-                }
-                &TenseVoiceMoodVariant::PresActIndEps(stm) => {
-                    let mut active_forms: Vec<String> = Vec::new();
-                    for ending in ["ω", "εις", "ει", "ουμεν", "ειτε", "ουσι"].iter()
-                    {
-                        let part = format!("{}{}", stm, ending);
-                        active_forms.push(part);
-                    }
-                    pai = Conjugated::Some(active_forms);
-                    let mut passive_forms: Vec<String> = Vec::new();
-                    for ending in ["ουμαι", "ῃ", "ειται", "ουμεθα", "εισθε", "ουνται"].iter()
-                    {
-                        let part = format!("{}{}", stm, ending);
-                        passive_forms.push(part);
-                    }
-                    ppi = Conjugated::Some(passive_forms);
-                    // imperfects
-                    let (aug, stem) = VerbStemSet::aug_and_stem(&self.aorist_stem, &stm);
-                    let mut impf_active_forms: Vec<String> = Vec::new();
-                    for ending in ["ουν", "εις", "ει", "ουμεν", "ειτε", "ουν"].iter()
-                    {
-                        let part = format!("{}{}{}", aug, stem, ending);
-                        impf_active_forms.push(part);
-                    }
-                    iai = Conjugated::Some(impf_active_forms);
-                    let mut impf_passive_forms: Vec<String> = Vec::new();
-                    for ending in ["ουμην", "ου", "ειτο", "ουμεθα", "εισθε", "ουντο"].iter()
-                    {
-                        let part = format!("{}{}{}", aug, stem, ending);
-                        impf_passive_forms.push(part);
-                    }
-                    ipi = Conjugated::Some(impf_passive_forms);
-                }
-                &TenseVoiceMoodVariant::PresActIndOmi(stm) => {
-                    let mut active_forms: Vec<String> = Vec::new();
-                    for ending in ["ω", "οις", "οι", "ουμεν", "ουτε", "ουσι"].iter()
-                    {
-                        let part = format!("{}{}", stm, ending);
-                        active_forms.push(part);
-                    }
-                    pai = Conjugated::Some(active_forms);
-                    let mut passive_forms: Vec<String> = Vec::new();
-                    for ending in ["ουμαι", "ου", "ουται", "ουμεθα", "ουσθε", "ουνται"].iter()
-                    {
-                        let part = format!("{}{}", stm, ending);
-                        passive_forms.push(part);
-                    }
-                    ppi = Conjugated::Some(passive_forms);
+            Some(stmtype) => match stmtype {
+                TenseVoiceMoodVariant::PresActIndAlp(stm)
+                | TenseVoiceMoodVariant::PresActIndEps(stm)
+                | TenseVoiceMoodVariant::PresActIndOmi(stm) => {
+                    let class = self.contraction(stmtype);
+                    pai = VerbStemSet::inflect(
+                        "",
+                        &class.forms(
+                            stm,
+                            &["ω", "εις", "ει", "ομεν", "ετε", "ουσι", "ετον", "ετον"],
+                        ),
+                    );
+                    ppi = VerbStemSet::inflect(
+                        "",
+                        &class.forms(
+                            stm,
+                            &[
+                                "ομαι",
+                                "ῃ",
+                                "εται",
+                                "ομεθα",
+                                "εσθε",
+                                "ονται",
+                                "εσθον",
+                                "εσθον",
+                            ],
+                        ),
+                    );
                     // imperfects
-                    let (aug, stem) = VerbStemSet::aug_and_stem(&self.aorist_stem, &stm);
-                    let mut impf_active_forms: Vec<String> = Vec::new();
-                    for ending in ["ουν", "ους", "ου", "ουμεν", "ουτε", "ουν"].iter()
-                    {
-                        let part = format!("{}{}{}", aug, stem, ending);
-                        impf_active_forms.push(part);
-                    }
-                    iai = Conjugated::Some(impf_active_forms);
-                    let mut impf_passive_forms: Vec<String> = Vec::new();
-                    for ending in ["ουμην", "ου", "ουτο", "ουμεθα", "ουσθε", "ουντο"].iter()
-                    {
-                        let part = format!("{}{}{}", aug, stem, ending);
-                        impf_passive_forms.push(part);
-                    }
-                    ipi = Conjugated::Some(impf_passive_forms);
+                    let (aug, stem) = self.aug_and_stem(stm);
+                    let stem = format!("{}{}", aug, stem);
+                    iai = VerbStemSet::inflect(
+                        "",
+                        &class.forms(
+                            &stem,
+                            &["ον", "ες", "ε", "ομεν", "ετε", "ον", "ετον", "ετην"],
+                        ),
+                    );
+                    ipi = VerbStemSet::inflect(
+                        "",
+                        &class.forms(
+                            &stem,
+                            &[
+                                "ομην",
+                                "ου",
+                                "ετο",
+                                "ομεθα",
+                                "εσθε",
+                                "οντο",
+                                "εσθον",
+                                "εσθην",
+                            ],
+                        ),
+                    );
                 }
-                &TenseVoiceMoodVariant::PresActIndReg(stm) => {
+                TenseVoiceMoodVariant::PresActIndReg(stm) => {
                     let mut active_forms: Vec<String> = Vec::new();
-                    for ending in ["ω", "εις", "ει", "ομεν", "ετε", "ουσι"].iter()
+                    for ending in ["ω", "εις", "ει", "ομεν", "ετε", "ουσι", "ετον", "ετον"].iter()
                     {
                         let part = format!("{}{}", stm, ending);
                         active_forms.push(part);
                     }
                     pai = Conjugated::Some(active_forms);
                     let mut passive_forms: Vec<String> = Vec::new();
-                    for ending in ["ομαι", "ῃ", "εται", "ομεθα", "εσθε", "ονται"].iter()
+                    for ending in [
+                        "ομαι",
+                        "ῃ",
+                        "εται",
+                        "ομεθα",
+                        "εσθε",
+                        "ονται",
+                        "εσθον",
+                        "εσθον",
+                    ]
+                    .iter()
                     {
                         let part = format!("{}{}", stm, ending);
                         passive_forms.push(part);
                     }
                     ppi = Conjugated::Some(passive_forms);
                     // imperfects
-                    let (aug, stem) = VerbStemSet::aug_and_stem(&self.aorist_stem, &stm);
+                    let (aug, stem) = self.aug_and_stem(stm);
                     let mut impf_active_forms: Vec<String> = Vec::new();
-                    for ending in ["ον", "ες", "ε", "ομεν", "ετε", "ον"].iter() {
+                    for ending in ["ον", "ες", "ε", "ομεν", "ετε", "ον", "ετον", "ετην"].iter()
+                    {
                         let part = format!("{}{}{}", aug, stem, ending);
                         impf_active_forms.push(part);
                     }
                     iai = Conjugated::Some(impf_active_forms);
                     let mut impf_passive_forms: Vec<String> = Vec::new();
-                    for ending in ["ομην", "ου", "ετο", "ομεθα", "εσθε", "οντο"].iter()
+                    for ending in [
+                        "ομην",
+                        "ου",
+                        "ετο",
+                        "ομεθα",
+                        "εσθε",
+                        "οντο",
+                        "εσθον",
+                        "εσθην",
+                    ]
+                    .iter()
                     {
                         let part = format!("{}{}{}", aug, stem, ending);
                         impf_passive_forms.push(part);
                     }
                     ipi = Conjugated::Some(impf_passive_forms);
                 }
-                &TenseVoiceMoodVariant::PresPassIndAlp(stm) => {
+                TenseVoiceMoodVariant::PresPassIndAlp(stm)
+                | TenseVoiceMoodVariant::PresPassIndEps(stm)
+                | TenseVoiceMoodVariant::PresPassIndOmi(stm) => {
+                    let class = self.contraction(stmtype);
                     pai = Conjugated::None;
-                    let mut passive_forms: Vec<String> = Vec::new();
-                    for ending in ["ωμαι", "ῳ", "αται", "ωμεθα", "ασθε", "ωνται"].iter()
-                    {
-                        let part = format!("{}{}", stm, ending);
-                        passive_forms.push(part);
-                    }
-                    ppi = Conjugated::Some(passive_forms);
+                    ppi = VerbStemSet::inflect(
+                        "",
+                        &class.forms(
+                            stm,
+                            &[
+                                "ομαι",
+                                "ῃ",
+                                "εται",
+                                "ομεθα",
+                                "εσθε",
+                                "ονται",
+                                "εσθον",
+                                "εσθον",
+                            ],
+                        ),
+                    );
                     // Imperfects
-                    let (aug, stem) = VerbStemSet::aug_and_stem(&self.aorist_stem, &stm);
+                    let (aug, stem) = self.aug_and_stem(stm);
                     iai = Conjugated::None;
-                    let mut impf_passive_forms: Vec<String> = Vec::new();
-                    for ending in ["ωμην", "ω", "ατο", "ωμεθα", "ασθε", "ωντο"].iter()
-                    {
-                        let part = format!("{}{}{}", aug, stem, ending);
-                        impf_passive_forms.push(part);
-                    }
-                    ipi = Conjugated::Some(impf_passive_forms);
+                    ipi = VerbStemSet::inflect(
+                        "",
+                        &class.forms(
+                            &format!("{}{}", aug, stem),
+                            &[
+                                "ομην",
+                                "ου",
+                                "ετο",
+                                "ομεθα",
+                                "εσθε",
+                                "οντο",
+                                "εσθον",
+                                "εσθην",
+                            ],
+                        ),
+                    );
                 }
-                &TenseVoiceMoodVariant::PresPassIndEps(stm) => {
+                TenseVoiceMoodVariant::PresPassIndReg(stm) => {
                     pai = Conjugated::None;
                     let mut passive_forms: Vec<String> = Vec::new();
-                    for ending in ["ουμαι", "ῃ", "ειται", "ουμεθα", "εισθε", "ουνται"].iter()
+                    for ending in [
+                        "ομαι",
+                        "ῃ",
+                        "εται",
+                        "ομεθα",
+                        "εσθε",
+                        "ονται",
+                        "εσθον",
+                        "εσθον",
+                    ]
+                    .iter()
                     {
                         let part = format!("{}{}", stm, ending);
                         passive_forms.push(part);
                     }
                     ppi = Conjugated::Some(passive_forms);
                     // Imperfects
-                    let (aug, stem) = VerbStemSet::aug_and_stem(&self.aorist_stem, &stm);
+                    let (aug, stem) = self.aug_and_stem(stm);
                     iai = Conjugated::None;
                     let mut impf_passive_forms: Vec<String> = Vec::new();
-                    for ending in ["ουμην", "ου", "ειτο", "ουμεθα", "εισθε", "ουντο"].iter()
+                    for ending in [
+                        "ομην",
+                        "ου",
+                        "ετο",
+                        "ομεθα",
+                        "εσθε",
+                        "οντο",
+                        "εσθον",
+                        "εσθην",
+                    ]
+                    .iter()
                     {
                         let part = format!("{}{}{}", aug, stem, ending);
                         impf_passive_forms.push(part);
                     }
                     ipi = Conjugated::Some(impf_passive_forms);
                 }
-                &TenseVoiceMoodVariant::PresPassIndOmi(stm) => {
-                    pai = Conjugated::None;
-                    let mut passive_forms: Vec<String> = Vec::new();
-                    for ending in ["ουμαι", "ου", "ουται", "ουμεθα", "ουσθε", "ουνται"].iter()
-                    {
-                        let part = format!("{}{}", stm, ending);
-                        passive_forms.push(part);
-                    }
-                    ppi = Conjugated::Some(passive_forms);
+                TenseVoiceMoodVariant::PresActIndMiOmi(stm) => {
+                    pai = VerbStemSet::inflect(
+                        stm,
+                        &["ωμι", "ως", "ωσι", "ομεν", "οτε", "οασι", "οτον", "οτον"],
+                    );
+                    ppi = VerbStemSet::inflect(
+                        stm,
+                        &[
+                            "ομαι",
+                            "οσαι",
+                            "οται",
+                            "ομεθα",
+                            "οσθε",
+                            "ονται",
+                            "οσθον",
+                            "οσθον",
+                        ],
+                    );
                     // Imperfects
-                    let (aug, stem) = VerbStemSet::aug_and_stem(&self.aorist_stem, &stm);
-                    iai = Conjugated::None;
-                    let mut impf_passive_forms: Vec<String> = Vec::new();
-                    for ending in ["ουμην", "ου", "ουτο", "ουμεθα", "ουσθε", "ουντο"].iter()
-                    {
-                        let part = format!("{}{}{}", aug, stem, ending);
-                        impf_passive_forms.push(part);
-                    }
-                    ipi = Conjugated::Some(impf_passive_forms);
+                    let (aug, stem) = self.aug_and_stem(stm);
+                    let stem = format!("{}{}", aug, stem);
+                    iai = VerbStemSet::inflect(
+                        &stem,
+                        &["ουν", "ους", "ου", "ομεν", "οτε", "οσαν", "οτον", "οτην"],
+                    );
+                    ipi = VerbStemSet::inflect(
+                        &stem,
+                        &[
+                            "ομην",
+                            "οσο",
+                            "οτο",
+                            "ομεθα",
+                            "οσθε",
+                            "οντο",
+                            "οσθον",
+                            "οσθην",
+                        ],
+                    );
                 }
-                &TenseVoiceMoodVariant::PresPassIndReg(stm) => {
-                    pai = Conjugated::None;
-                    let mut passive_forms: Vec<String> = Vec::new();
-                    for ending in ["ομαι", "ῃ", "εται", "ομεθα", "εσθε", "ονται"].iter()
-                    {
-                        let part = format!("{}{}", stm, ending);
-                        passive_forms.push(part);
-                    }
-                    ppi = Conjugated::Some(passive_forms);
+                TenseVoiceMoodVariant::PresActIndMiEps(stm) => {
+                    // ἱε-ασι contracts to ἱᾶσι
+                    let third_pl = if stm.ends_with('ἱ') || stm.ends_with('ι') {
+                        "ᾶσι"
+                    } else {
+                        "εασι"
+                    };
+                    pai = VerbStemSet::inflect(
+                        stm,
+                        &["ημι", "ης", "ησι", "εμεν", "ετε", third_pl, "ετον", "ετον"],
+                    );
+                    ppi = VerbStemSet::inflect(
+                        stm,
+                        &[
+                            "εμαι",
+                            "εσαι",
+                            "εται",
+                            "εμεθα",
+                            "εσθε",
+                            "ενται",
+                            "εσθον",
+                            "εσθον",
+                        ],
+                    );
                     // Imperfects
-                    let (aug, stem) = VerbStemSet::aug_and_stem(&self.aorist_stem, &stm);
-                    iai = Conjugated::None;
-                    let mut impf_passive_forms: Vec<String> = Vec::new();
-                    for ending in ["ομην", "ου", "ετο", "ομεθα", "εσθε", "οντο"].iter()
-                    {
-                        let part = format!("{}{}{}", aug, stem, ending);
-                        impf_passive_forms.push(part);
-                    }
-                    ipi = Conjugated::Some(impf_passive_forms);
+                    let (aug, stem) = self.aug_and_stem(stm);
+                    let stem = format!("{}{}", aug, stem);
+                    iai = VerbStemSet::inflect(
+                        &stem,
+                        &["ην", "εις", "ει", "εμεν", "ετε", "εσαν", "ετον", "ετην"],
+                    );
+                    ipi = VerbStemSet::inflect(
+                        &stem,
+                        &[
+                            "εμην",
+                            "εσο",
+                            "ετο",
+                            "εμεθα",
+                            "εσθε",
+                            "εντο",
+                            "εσθον",
+                            "εσθην",
+                        ],
+                    );
+                }
+                TenseVoiceMoodVariant::PresActIndMiAlp(stm) => {
+                    // ἱστα-ασι contracts to ἱστᾶσι
+                    pai = VerbStemSet::inflect(
+                        stm,
+                        &["ημι", "ης", "ησι", "αμεν", "ατε", "ᾶσι", "ατον", "ατον"],
+                    );
+                    ppi = VerbStemSet::inflect(
+                        stm,
+                        &[
+                            "αμαι",
+                            "ασαι",
+                            "αται",
+                            "αμεθα",
+                            "ασθε",
+                            "ανται",
+                            "ασθον",
+                            "ασθον",
+                        ],
+                    );
+                    // Imperfects
+                    let (aug, stem) = self.aug_and_stem(stm);
+                    let stem = format!("{}{}", aug, stem);
+                    iai = VerbStemSet::inflect(
+                        &stem,
+                        &["ην", "ης", "η", "αμεν", "ατε", "ασαν", "ατον", "ατην"],
+                    );
+                    ipi = VerbStemSet::inflect(
+                        &stem,
+                        &[
+                            "αμην",
+                            "ασο",
+                            "ατο",
+                            "αμεθα",
+                            "ασθε",
+                            "αντο",
+                            "ασθον",
+                            "ασθην",
+                        ],
+                    );
+                }
+                TenseVoiceMoodVariant::PresActIndMiUps(stm) => {
+                    pai = VerbStemSet::inflect(
+                        stm,
+                        &["υμι", "υς", "υσι", "υμεν", "υτε", "υασι", "υτον", "υτον"],
+                    );
+                    ppi = VerbStemSet::inflect(
+                        stm,
+                        &[
+                            "υμαι",
+                            "υσαι",
+                            "υται",
+                            "υμεθα",
+                            "υσθε",
+                            "υνται",
+                            "υσθον",
+                            "υσθον",
+                        ],
+                    );
+                    // Imperfects
+                    let (aug, stem) = self.aug_and_stem(stm);
+                    let stem = format!("{}{}", aug, stem);
+                    iai = VerbStemSet::inflect(
+                        &stem,
+                        &["υν", "υς", "υ", "υμεν", "υτε", "υσαν", "υτον", "υτην"],
+                    );
+                    ipi = VerbStemSet::inflect(
+                        &stem,
+                        &[
+                            "υμην",
+                            "υσο",
+                            "υτο",
+                            "υμεθα",
+                            "υσθε",
+                            "υντο",
+                            "υσθον",
+                            "υσθην",
+                        ],
+                    );
                 }
+                TenseVoiceMoodVariant::PresIndIrr(pp) => match irregular::find_present(pp) {
+                    Some(irr) => {
+                        pai = VerbStemSet::inflect("", &irr.indicative);
+                        ppi = Conjugated::None;
+                        iai = VerbStemSet::inflect("", &irr.imperfect);
+                        ipi = Conjugated::None;
+                    }
+                    None => {
+                        pai = Conjugated::None;
+                        ppi = Conjugated::None;
+                        iai = Conjugated::None;
+                        ipi = Conjugated::None;
+                    }
+                },
                 _ => {
                     pai = Conjugated::None;
                     ppi = Conjugated::None;
@@ -1030,29 +3305,39 @@ impl VerbStemSet {
         let fai: Conjugated;
         let fmi: Conjugated;
         match &self.fut_stem {
-            Some(stmtype) => match &stmtype {
-                &TenseVoiceMoodVariant::FutActIndEps(stm) => {
+            Some(stmtype) => match stmtype {
+                TenseVoiceMoodVariant::FutActIndEps(stm) => {
+                    let class = self.contraction(stmtype);
                     // Future actives
-                    let mut active_forms: Vec<String> = Vec::new();
-                    for ending in ["ω", "εις", "ει", "ουμεν", "ειτε", "ουσι"].iter()
-                    {
-                        let part = format!("{}{}", stm, ending);
-                        active_forms.push(part);
-                    }
-                    fai = Conjugated::Some(active_forms);
+                    fai = VerbStemSet::inflect(
+                        "",
+                        &class.forms(
+                            stm,
+                            &["ω", "εις", "ει", "ομεν", "ετε", "ουσι", "ετον", "ετον"],
+                        ),
+                    );
                     // Future middles
-                    let mut middle_forms: Vec<String> = Vec::new();
-                    for ending in ["ουμαι", "ῃ", "ειται", "ουμεθα", "εισθε", "ουνται"].iter()
-                    {
-                        let part = format!("{}{}", stm, ending);
-                        middle_forms.push(part);
-                    }
-                    fmi = Conjugated::Some(middle_forms);
+                    fmi = VerbStemSet::inflect(
+                        "",
+                        &class.forms(
+                            stm,
+                            &[
+                                "ομαι",
+                                "ῃ",
+                                "εται",
+                                "ομεθα",
+                                "εσθε",
+                                "ονται",
+                                "εσθον",
+                                "εσθον",
+                            ],
+                        ),
+                    );
                 }
-                &TenseVoiceMoodVariant::FutActIndReg(stm) => {
+                TenseVoiceMoodVariant::FutActIndReg(stm) => {
                     // Future actives
                     let mut active_forms: Vec<String> = Vec::new();
-                    for ending in ["ω", "εις", "ει", "ομεν", "ετε", "ουσι"].iter()
+                    for ending in ["ω", "εις", "ει", "ομεν", "ετε", "ουσι", "ετον", "ετον"].iter()
                     {
                         let part = format!("{}{}", stm, ending);
                         active_forms.push(part);
@@ -1060,38 +3345,68 @@ impl VerbStemSet {
                     fai = Conjugated::Some(active_forms);
                     // Future middles
                     let mut middle_forms: Vec<String> = Vec::new();
-                    for ending in ["ομαι", "ῃ", "εται", "ομεθα", "εσθε", "ονται"].iter()
+                    for ending in [
+                        "ομαι",
+                        "ῃ",
+                        "εται",
+                        "ομεθα",
+                        "εσθε",
+                        "ονται",
+                        "εσθον",
+                        "εσθον",
+                    ]
+                    .iter()
                     {
                         let part = format!("{}{}", stm, ending);
                         middle_forms.push(part);
                     }
                     fmi = Conjugated::Some(middle_forms);
                 }
-                &TenseVoiceMoodVariant::FutMidIndEps(stm) => {
+                TenseVoiceMoodVariant::FutMidIndEps(stm) => {
+                    let class = self.contraction(stmtype);
                     // Future actives
                     fai = Conjugated::None;
                     // Future middles
-                    let mut middle_forms: Vec<String> = Vec::new();
-                    for ending in ["ουμαι", "ῃ", "ειται", "ουμεθα", "εισθε", "ουνται"].iter()
-                    {
-                        let part = format!("{}{}", stm, ending);
-                        middle_forms.push(part);
-                    }
-                    fmi = Conjugated::Some(middle_forms);
+                    fmi = VerbStemSet::inflect(
+                        "",
+                        &class.forms(
+                            stm,
+                            &[
+                                "ομαι",
+                                "ῃ",
+                                "εται",
+                                "ομεθα",
+                                "εσθε",
+                                "ονται",
+                                "εσθον",
+                                "εσθον",
+                            ],
+                        ),
+                    );
                 }
-                &TenseVoiceMoodVariant::FutMidIndReg(stm) => {
+                TenseVoiceMoodVariant::FutMidIndReg(stm) => {
                     // Future actives
                     fai = Conjugated::None;
                     // Future middles
                     let mut middle_forms: Vec<String> = Vec::new();
-                    for ending in ["ομαι", "ῃ", "εται", "ομεθα", "εσθε", "ονται"].iter()
+                    for ending in [
+                        "ομαι",
+                        "ῃ",
+                        "εται",
+                        "ομεθα",
+                        "εσθε",
+                        "ονται",
+                        "εσθον",
+                        "εσθον",
+                    ]
+                    .iter()
                     {
                         let part = format!("{}{}", stm, ending);
                         middle_forms.push(part);
                     }
                     fmi = Conjugated::Some(middle_forms);
                 }
-                &TenseVoiceMoodVariant::FutPassIndReg(stm) => {
+                TenseVoiceMoodVariant::FutPassIndReg(stm) => {
                     // Deponent Future
                     let mut passive_forms: Vec<String> = Vec::new();
                     for ending in [
@@ -1101,6 +3416,8 @@ impl VerbStemSet {
                         "θησομεθα",
                         "θησεσθε",
                         "θησονται",
+                        "θησεσθον",
+                        "θησεσθον",
                     ]
                     .iter()
                     {
@@ -1111,6 +3428,20 @@ impl VerbStemSet {
                     // Future middles
                     fmi = Conjugated::None;
                 }
+                TenseVoiceMoodVariant::FutIndIrr(pp) => match irregular::find_future(pp) {
+                    Some(irr) if irr.middle => {
+                        fai = Conjugated::None;
+                        fmi = VerbStemSet::inflect("", &irr.indicative);
+                    }
+                    Some(irr) => {
+                        fai = VerbStemSet::inflect("", &irr.indicative);
+                        fmi = Conjugated::None;
+                    }
+                    None => {
+                        fai = Conjugated::None;
+                        fmi = Conjugated::None;
+                    }
+                },
                 _ => {
                     fai = Conjugated::None;
                     fmi = Conjugated::None;
@@ -1126,29 +3457,39 @@ impl VerbStemSet {
         let fai2: Conjugated;
         let fmi2: Conjugated;
         match &self.fut_stem_alt {
-            Some(stmtype) => match &stmtype {
-                &TenseVoiceMoodVariant::FutActIndEps(stm) => {
+            Some(stmtype) => match stmtype {
+                TenseVoiceMoodVariant::FutActIndEps(stm) => {
+                    let class = self.contraction(stmtype);
                     // Future actives
-                    let mut active_forms: Vec<String> = Vec::new();
-                    for ending in ["ω", "εις", "ει", "ουμεν", "ειτε", "ουσι"].iter()
-                    {
-                        let part = format!("{}{}", stm, ending);
-                        active_forms.push(part);
-                    }
-                    fai2 = Conjugated::Some(active_forms);
+                    fai2 = VerbStemSet::inflect(
+                        "",
+                        &class.forms(
+                            stm,
+                            &["ω", "εις", "ει", "ομεν", "ετε", "ουσι", "ετον", "ετον"],
+                        ),
+                    );
                     // Future middles
-                    let mut middle_forms: Vec<String> = Vec::new();
-                    for ending in ["ουμαι", "ῃ", "ειται", "ουμεθα", "εισθε", "ουνται"].iter()
-                    {
-                        let part = format!("{}{}", stm, ending);
-                        middle_forms.push(part);
-                    }
-                    fmi2 = Conjugated::Some(middle_forms);
+                    fmi2 = VerbStemSet::inflect(
+                        "",
+                        &class.forms(
+                            stm,
+                            &[
+                                "ομαι",
+                                "ῃ",
+                                "εται",
+                                "ομεθα",
+                                "εσθε",
+                                "ονται",
+                                "εσθον",
+                                "εσθον",
+                            ],
+                        ),
+                    );
                 }
-                &TenseVoiceMoodVariant::FutActIndReg(stm) => {
+                TenseVoiceMoodVariant::FutActIndReg(stm) => {
                     // Future actives
                     let mut active_forms: Vec<String> = Vec::new();
-                    for ending in ["ω", "εις", "ει", "ομεν", "ετε", "ουσι"].iter()
+                    for ending in ["ω", "εις", "ει", "ομεν", "ετε", "ουσι", "ετον", "ετον"].iter()
                     {
                         let part = format!("{}{}", stm, ending);
                         active_forms.push(part);
@@ -1156,38 +3497,68 @@ impl VerbStemSet {
                     fai2 = Conjugated::Some(active_forms);
                     // Future middles
                     let mut middle_forms: Vec<String> = Vec::new();
-                    for ending in ["ομαι", "ῃ", "εται", "ομεθα", "εσθε", "ονται"].iter()
+                    for ending in [
+                        "ομαι",
+                        "ῃ",
+                        "εται",
+                        "ομεθα",
+                        "εσθε",
+                        "ονται",
+                        "εσθον",
+                        "εσθον",
+                    ]
+                    .iter()
                     {
                         let part = format!("{}{}", stm, ending);
                         middle_forms.push(part);
                     }
                     fmi2 = Conjugated::Some(middle_forms);
                 }
-                &TenseVoiceMoodVariant::FutMidIndEps(stm) => {
+                TenseVoiceMoodVariant::FutMidIndEps(stm) => {
+                    let class = self.contraction(stmtype);
                     // Future actives
                     fai2 = Conjugated::None;
                     // Future middles
-                    let mut middle_forms: Vec<String> = Vec::new();
-                    for ending in ["ουμαι", "ῃ", "ειται", "ουμεθα", "εισθε", "ουνται"].iter()
-                    {
-                        let part = format!("{}{}", stm, ending);
-                        middle_forms.push(part);
-                    }
-                    fmi2 = Conjugated::Some(middle_forms);
+                    fmi2 = VerbStemSet::inflect(
+                        "",
+                        &class.forms(
+                            stm,
+                            &[
+                                "ομαι",
+                                "ῃ",
+                                "εται",
+                                "ομεθα",
+                                "εσθε",
+                                "ονται",
+                                "εσθον",
+                                "εσθον",
+                            ],
+                        ),
+                    );
                 }
-                &TenseVoiceMoodVariant::FutMidIndReg(stm) => {
+                TenseVoiceMoodVariant::FutMidIndReg(stm) => {
                     // Future actives
                     fai2 = Conjugated::None;
                     // Future middles
                     let mut middle_forms: Vec<String> = Vec::new();
-                    for ending in ["ομαι", "ῃ", "εται", "ομεθα", "εσθε", "ονται"].iter()
+                    for ending in [
+                        "ομαι",
+                        "ῃ",
+                        "εται",
+                        "ομεθα",
+                        "εσθε",
+                        "ονται",
+                        "εσθον",
+                        "εσθον",
+                    ]
+                    .iter()
                     {
                         let part = format!("{}{}", stm, ending);
                         middle_forms.push(part);
                     }
                     fmi2 = Conjugated::Some(middle_forms);
                 }
-                &TenseVoiceMoodVariant::FutPassIndReg(stm) => {
+                TenseVoiceMoodVariant::FutPassIndReg(stm) => {
                     // Deponent Futures
                     let mut passive_forms: Vec<String> = Vec::new();
                     for ending in [
@@ -1197,6 +3568,8 @@ impl VerbStemSet {
                         "θησομεθα",
                         "θησεσθε",
                         "θησονται",
+                        "θησεσθον",
+                        "θησεσθον",
                     ]
                     .iter()
                     {
@@ -1207,6 +3580,20 @@ impl VerbStemSet {
                     // Future middles
                     fmi2 = Conjugated::None;
                 }
+                TenseVoiceMoodVariant::FutIndIrr(pp) => match irregular::find_future(pp) {
+                    Some(irr) if irr.middle => {
+                        fai2 = Conjugated::None;
+                        fmi2 = VerbStemSet::inflect("", &irr.indicative);
+                    }
+                    Some(irr) => {
+                        fai2 = VerbStemSet::inflect("", &irr.indicative);
+                        fmi2 = Conjugated::None;
+                    }
+                    None => {
+                        fai2 = Conjugated::None;
+                        fmi2 = Conjugated::None;
+                    }
+                },
                 _ => {
                     fai2 = Conjugated::None;
                     fmi2 = Conjugated::None;
@@ -1222,76 +3609,125 @@ impl VerbStemSet {
         let aai: Conjugated;
         let ami: Conjugated;
         match &self.aorist_stem {
-            Some(stmtype) => match &stmtype {
-                &TenseVoiceMoodVariant::AorActIndStr(stm) => {
+            Some(stmtype) => match stmtype {
+                TenseVoiceMoodVariant::AorActIndStr(stm) => {
                     // Strong aorist actives
                     let mut active_forms: Vec<String> = Vec::new();
-                    for ending in ["ον", "ες", "ε", "ομεν", "ετε", "ον"].iter() {
+                    for ending in self.strong_aorist_endings().iter() {
                         let part = format!("{}{}", stm, ending);
                         active_forms.push(part);
                     }
                     aai = Conjugated::Some(active_forms);
                     // Strong aorist middles
                     let mut middle_forms: Vec<String> = Vec::new();
-                    for ending in ["ομην", "ου", "ετο", "ομεθα", "εσθε", "οντο"].iter()
+                    for ending in [
+                        "ομην",
+                        "ου",
+                        "ετο",
+                        "ομεθα",
+                        "εσθε",
+                        "οντο",
+                        "εσθον",
+                        "εσθην",
+                    ]
+                    .iter()
                     {
                         let part = format!("{}{}", stm, ending);
                         middle_forms.push(part);
                     }
                     ami = Conjugated::Some(middle_forms);
                 }
-                &TenseVoiceMoodVariant::AorActIndWk(stm) => {
+                TenseVoiceMoodVariant::AorActIndWk(stm) => {
                     // Weak aorist actives
                     let mut active_forms: Vec<String> = Vec::new();
-                    for ending in ["α", "ας", "ε", "αμεν", "ατε", "αν"].iter() {
+                    for ending in ["α", "ας", "ε", "αμεν", "ατε", "αν", "ατον", "ατην"].iter()
+                    {
                         let part = format!("{}{}", stm, ending);
                         active_forms.push(part);
                     }
                     aai = Conjugated::Some(active_forms);
                     // Weak aorist middles
                     let mut middle_forms: Vec<String> = Vec::new();
-                    for ending in ["αμην", "ω", "ατο", "αμεθα", "ασθε", "αντο"].iter()
+                    for ending in [
+                        "αμην",
+                        "ω",
+                        "ατο",
+                        "αμεθα",
+                        "ασθε",
+                        "αντο",
+                        "ασθον",
+                        "ασθην",
+                    ]
+                    .iter()
                     {
                         let part = format!("{}{}", stm, ending);
                         middle_forms.push(part);
                     }
                     ami = Conjugated::Some(middle_forms);
                 }
-                &TenseVoiceMoodVariant::AorMidIndStr(stm) => {
+                TenseVoiceMoodVariant::AorMidIndStr(stm) => {
                     // Strong aorist actives
                     aai = Conjugated::None;
                     // Strong aorist middles
                     let mut middle_forms: Vec<String> = Vec::new();
-                    for ending in ["ομην", "ου", "ετο", "ομεθα", "εσθε", "οντο"].iter()
+                    for ending in [
+                        "ομην",
+                        "ου",
+                        "ετο",
+                        "ομεθα",
+                        "εσθε",
+                        "οντο",
+                        "εσθον",
+                        "εσθην",
+                    ]
+                    .iter()
                     {
                         let part = format!("{}{}", stm, ending);
                         middle_forms.push(part);
                     }
                     ami = Conjugated::Some(middle_forms);
                 }
-                &TenseVoiceMoodVariant::AorMidIndWk(stm) => {
+                TenseVoiceMoodVariant::AorMidIndWk(stm) => {
                     // Weak aorist actives
                     aai = Conjugated::None;
                     // Weak aorist middles
                     let mut middle_forms: Vec<String> = Vec::new();
-                    for ending in ["αμην", "ω", "ατο", "αμεθα", "ασθε", "αντο"].iter()
+                    for ending in [
+                        "αμην",
+                        "ω",
+                        "ατο",
+                        "αμεθα",
+                        "ασθε",
+                        "αντο",
+                        "ασθον",
+                        "ασθην",
+                    ]
+                    .iter()
                     {
                         let part = format!("{}{}", stm, ending);
                         middle_forms.push(part);
                     }
                     ami = Conjugated::Some(middle_forms);
                 }
-                &TenseVoiceMoodVariant::AorPassIndWk(stm) => {
+                TenseVoiceMoodVariant::AorPassIndWk(stm)
+                | TenseVoiceMoodVariant::AorPassIndStr(stm) => {
                     // Passive Deponent aorists
                     // Weak aorist middles
                     let mut active_forms: Vec<String> = Vec::new();
-                    for ending in ["ην", "ης", "η", "ημεν", "ητε", "ησαν"].iter() {
+                    for ending in ["ην", "ης", "η", "ημεν", "ητε", "ησαν", "ητον", "ητην"].iter()
+                    {
                         let part = format!("{}{}", stm, ending);
                         active_forms.push(part);
                     }
                     aai = Conjugated::Some(active_forms);
                     ami = Conjugated::None;
                 }
+                TenseVoiceMoodVariant::AorActIndKap(_)
+                | TenseVoiceMoodVariant::AorActIndRoot(_) => {
+                    let (active, middle) = self.mi_aorists(stmtype);
+                    aai = active;
+                    ami = middle;
+                }
                 _ => {
                     aai = Conjugated::None;
                     ami = Conjugated::None;
@@ -1310,76 +3746,125 @@ impl VerbStemSet {
         let aai2: Conjugated;
         let ami2: Conjugated;
         match &self.aorist_stem_alt {
-            Some(stmtype) => match &stmtype {
-                &TenseVoiceMoodVariant::AorActIndStr(stm) => {
+            Some(stmtype) => match stmtype {
+                TenseVoiceMoodVariant::AorActIndStr(stm) => {
                     // Strong aorist actives
                     let mut active_forms: Vec<String> = Vec::new();
-                    for ending in ["ον", "ες", "ε", "ομεν", "ετε", "ον"].iter() {
+                    for ending in self.strong_aorist_endings().iter() {
                         let part = format!("{}{}", stm, ending);
                         active_forms.push(part);
                     }
                     aai2 = Conjugated::Some(active_forms);
                     // Strong aorist middles
                     let mut middle_forms: Vec<String> = Vec::new();
-                    for ending in ["ομην", "ου", "ετο", "ομεθα", "εσθε", "οντο"].iter()
+                    for ending in [
+                        "ομην",
+                        "ου",
+                        "ετο",
+                        "ομεθα",
+                        "εσθε",
+                        "οντο",
+                        "εσθον",
+                        "εσθην",
+                    ]
+                    .iter()
                     {
                         let part = format!("{}{}", stm, ending);
                         middle_forms.push(part);
                     }
                     ami2 = Conjugated::Some(middle_forms);
                 }
-                &TenseVoiceMoodVariant::AorActIndWk(stm) => {
+                TenseVoiceMoodVariant::AorActIndWk(stm) => {
                     // Weak aorist actives
                     let mut active_forms: Vec<String> = Vec::new();
-                    for ending in ["α", "ας", "ε", "αμεν", "ατε", "αν"].iter() {
+                    for ending in ["α", "ας", "ε", "αμεν", "ατε", "αν", "ατον", "ατην"].iter()
+                    {
                         let part = format!("{}{}", stm, ending);
                         active_forms.push(part);
                     }
                     aai2 = Conjugated::Some(active_forms);
                     // Weak aorist middles
                     let mut middle_forms: Vec<String> = Vec::new();
-                    for ending in ["αμην", "ω", "ατο", "αμεθα", "ασθε", "αντο"].iter()
+                    for ending in [
+                        "αμην",
+                        "ω",
+                        "ατο",
+                        "αμεθα",
+                        "ασθε",
+                        "αντο",
+                        "ασθον",
+                        "ασθην",
+                    ]
+                    .iter()
                     {
                         let part = format!("{}{}", stm, ending);
                         middle_forms.push(part);
                     }
                     ami2 = Conjugated::Some(middle_forms);
                 }
-                &TenseVoiceMoodVariant::AorMidIndStr(stm) => {
+                TenseVoiceMoodVariant::AorMidIndStr(stm) => {
                     // Strong aorist actives
                     aai2 = Conjugated::None;
                     // Strong aorist middles
                     let mut middle_forms: Vec<String> = Vec::new();
-                    for ending in ["ομην", "ου", "ετο", "ομεθα", "εσθε", "οντο"].iter()
+                    for ending in [
+                        "ομην",
+                        "ου",
+                        "ετο",
+                        "ομεθα",
+                        "εσθε",
+                        "οντο",
+                        "εσθον",
+                        "εσθην",
+                    ]
+                    .iter()
                     {
                         let part = format!("{}{}", stm, ending);
                         middle_forms.push(part);
                     }
                     ami2 = Conjugated::Some(middle_forms);
                 }
-                &TenseVoiceMoodVariant::AorMidIndWk(stm) => {
+                TenseVoiceMoodVariant::AorMidIndWk(stm) => {
                     // Weak aorist actives
                     aai2 = Conjugated::None;
                     // Weak aorist middles
                     let mut middle_forms: Vec<String> = Vec::new();
-                    for ending in ["αμην", "ω", "ατο", "αμεθα", "ασθε", "αντο"].iter()
+                    for ending in [
+                        "αμην",
+                        "ω",
+                        "ατο",
+                        "αμεθα",
+                        "ασθε",
+                        "αντο",
+                        "ασθον",
+                        "ασθην",
+                    ]
+                    .iter()
                     {
                         let part = format!("{}{}", stm, ending);
                         middle_forms.push(part);
                     }
                     ami2 = Conjugated::Some(middle_forms);
                 }
-                &TenseVoiceMoodVariant::AorPassIndWk(stm) => {
+                TenseVoiceMoodVariant::AorPassIndWk(stm)
+                | TenseVoiceMoodVariant::AorPassIndStr(stm) => {
                     // Deponent aorists
                     // Weak aorist middles
                     let mut active_forms: Vec<String> = Vec::new();
-                    for ending in ["ην", "ης", "η", "ημεν", "ητε", "ησαν"].iter() {
+                    for ending in ["ην", "ης", "η", "ημεν", "ητε", "ησαν", "ητον", "ητην"].iter()
+                    {
                         let part = format!("{}{}", stm, ending);
                         active_forms.push(part);
                     }
                     aai2 = Conjugated::Some(active_forms);
                     ami2 = Conjugated::None;
                 }
+                TenseVoiceMoodVariant::AorActIndKap(_)
+                | TenseVoiceMoodVariant::AorActIndRoot(_) => {
+                    let (active, middle) = self.mi_aorists(stmtype);
+                    aai2 = active;
+                    ami2 = middle;
+                }
                 _ => {
                     aai2 = Conjugated::None;
                     ami2 = Conjugated::None;
@@ -1399,10 +3884,12 @@ impl VerbStemSet {
         let fpi: Conjugated;
         match &self.aor_pass_stem {
             Some(stmtype) => match &stmtype {
-                &TenseVoiceMoodVariant::AorPassIndWk(stm) => {
+                &TenseVoiceMoodVariant::AorPassIndWk(stm)
+                | &TenseVoiceMoodVariant::AorPassIndStr(stm) => {
                     // aorist passives
                     let mut passive_forms: Vec<String> = Vec::new();
-                    for ending in ["ην", "ης", "η", "ημεν", "ητε", "ησαν"].iter() {
+                    for ending in ["ην", "ης", "η", "ημεν", "ητε", "ησαν", "ητον", "ητην"].iter()
+                    {
                         let part = format!("{}{}", stm, ending);
                         passive_forms.push(part);
                     }
@@ -1410,9 +3897,19 @@ impl VerbStemSet {
                     // future passives
                     let mut fut_pass_forms: Vec<String> = Vec::new();
                     // println!("AP stem before: {}", stm);
-                    let stem = VerbStemSet::remove_aug(&self.pres_stem, stm);
+                    let stem = self.remove_aug(stm);
                     // println!("AP stem after: {}", stem);
-                    for ending in ["ησομαι", "ησῃ", "ησεται", "ησομεθα", "ησεσθε", "ησονται"].iter()
+                    for ending in [
+                        "ησομαι",
+                        "ησῃ",
+                        "ησεται",
+                        "ησομεθα",
+                        "ησεσθε",
+                        "ησονται",
+                        "ησεσθον",
+                        "ησεσθον",
+                    ]
+                    .iter()
                     {
                         let part = format!("{}{}", stem, ending);
                         fut_pass_forms.push(part);
@@ -1435,18 +3932,30 @@ impl VerbStemSet {
         let fpi2: Conjugated;
         match &self.aor_pass_stem_alt {
             Some(stmtype) => match &stmtype {
-                &TenseVoiceMoodVariant::AorPassIndWk(stm) => {
+                &TenseVoiceMoodVariant::AorPassIndWk(stm)
+                | &TenseVoiceMoodVariant::AorPassIndStr(stm) => {
                     // aorist passives
                     let mut passive_forms: Vec<String> = Vec::new();
-                    for ending in ["ην", "ης", "η", "ημεν", "ητε", "ησαν"].iter() {
+                    for ending in ["ην", "ης", "η", "ημεν", "ητε", "ησαν", "ητον", "ητην"].iter()
+                    {
                         let part = format!("{}{}", stm, ending);
                         passive_forms.push(part);
                     }
                     api2 = Conjugated::Some(passive_forms);
                     // future passives
                     let mut fut_pass_forms: Vec<String> = Vec::new();
-                    let stem = VerbStemSet::remove_aug(&self.pres_stem, stm);
-                    for ending in ["ησομαι", "ησῃ", "ησεται", "ησομεθα", "ησεσθε", "ησονται"].iter()
+                    let stem = self.remove_aug(stm);
+                    for ending in [
+                        "ησομαι",
+                        "ησῃ",
+                        "ησεται",
+                        "ησομεθα",
+                        "ησεσθε",
+                        "ησονται",
+                        "ησεσθον",
+                        "ησεσθον",
+                    ]
+                    .iter()
                     {
                         let part = format!("{}{}", stem, ending);
                         fut_pass_forms.push(part);
@@ -1465,24 +3974,186 @@ impl VerbStemSet {
             }
         }
 
-        Verb {
-            pai: pai,
-            ppi: ppi,
-            iai: iai,
-            ipi: ipi,
-            fai: fai,
-            fai2: fai2,
-            fmi: fmi,
-            fmi2: fmi2,
-            fpi: fpi,
-            fpi2: fpi2,
-            aai: aai,
-            aai2: aai2,
-            ami: ami,
-            ami2: ami2,
-            api: api,
-            api2: api2,
+        // Subjunctives
+        let (pas, pps) = self.pres_subjunctives();
+        let (aas, ams) = self.aor_subjunctives(&self.aorist_stem);
+        let (aas2, ams2) = self.aor_subjunctives(&self.aorist_stem_alt);
+        let aps = self.aor_pass_subjunctive(&self.aor_pass_stem);
+        let aps2 = self.aor_pass_subjunctive(&self.aor_pass_stem_alt);
+
+        // Optatives
+        let (pao, ppo) = self.pres_optatives();
+        let (fao, fmo) = self.fut_optatives(&self.fut_stem);
+        let (fao2, fmo2) = self.fut_optatives(&self.fut_stem_alt);
+        let fpo = self.fut_pass_optative(&self.aor_pass_stem);
+        let fpo2 = self.fut_pass_optative(&self.aor_pass_stem_alt);
+        let (aao, amo) = self.aor_optatives(&self.aorist_stem);
+        let (aao2, amo2) = self.aor_optatives(&self.aorist_stem_alt);
+        let apo = self.aor_pass_optative(&self.aor_pass_stem);
+        let apo2 = self.aor_pass_optative(&self.aor_pass_stem_alt);
+
+        // Imperatives
+        let (pam, ppm) = self.pres_imperatives();
+        let (aam, amm) = self.aor_imperatives(&self.aorist_stem);
+        let (aam2, amm2) = self.aor_imperatives(&self.aorist_stem_alt);
+        let apm = self.aor_pass_imperative(&self.aor_pass_stem);
+        let apm2 = self.aor_pass_imperative(&self.aor_pass_stem_alt);
+
+        // Infinitives
+        let (pan, ppn) = self.pres_infinitives();
+        let (fan, fmn) = self.fut_infinitives(&self.fut_stem);
+        let (fan2, fmn2) = self.fut_infinitives(&self.fut_stem_alt);
+        let fpn = self.fut_pass_infinitive(&self.aor_pass_stem);
+        let fpn2 = self.fut_pass_infinitive(&self.aor_pass_stem_alt);
+        let (aan, amn) = self.aor_infinitives(&self.aorist_stem);
+        let (aan2, amn2) = self.aor_infinitives(&self.aorist_stem_alt);
+        let apn = self.aor_pass_infinitive(&self.aor_pass_stem);
+        let apn2 = self.aor_pass_infinitive(&self.aor_pass_stem_alt);
+
+        // Perfects and pluperfects
+        let (rai, lai) = self.perf_actives(&self.perf_stem);
+        let (rai2, lai2) = self.perf_actives(&self.perf_stem_alt);
+        let (rmi, lmi) = self.perf_middles(&self.perf_mid_stem);
+        let (rmi2, lmi2) = self.perf_middles(&self.perf_mid_stem_alt);
+
+        // Participles
+        let (pap, ppp) = self.pres_participles();
+        let (fap, fmp) = self.fut_participles(&self.fut_stem);
+        let (fap2, fmp2) = self.fut_participles(&self.fut_stem_alt);
+        let fpp = self.fut_pass_participle(&self.aor_pass_stem);
+        let fpp2 = self.fut_pass_participle(&self.aor_pass_stem_alt);
+        let (aap, amp) = self.aor_participles(&self.aorist_stem);
+        let (aap2, amp2) = self.aor_participles(&self.aorist_stem_alt);
+        let app = self.aor_pass_participle(&self.aor_pass_stem);
+        let app2 = self.aor_pass_participle(&self.aor_pass_stem_alt);
+
+        // Iteratives
+        let (iterative, iterative_mid) = self.iteratives();
+
+        // Verbal adjectives; a passive deponent's aorist passive is in its
+        // aorist column
+        let ap = match self.aor_pass_stem {
+            Some(_) => &self.aor_pass_stem,
+            None => &self.aorist_stem,
+        };
+        let (teos, tos) = self.verbal_adjectives(ap);
+        let (teos2, tos2) = self.verbal_adjectives(&self.aor_pass_stem_alt);
+
+        let mut verb = Verb {
+            pai,
+            ppi,
+            iai,
+            ipi,
+            fai,
+            fai2,
+            fmi,
+            fmi2,
+            fpi,
+            fpi2,
+            aai,
+            aai2,
+            ami,
+            ami2,
+            api,
+            api2,
+            pas,
+            pps,
+            aas,
+            aas2,
+            ams,
+            ams2,
+            aps,
+            aps2,
+            pao,
+            ppo,
+            fao,
+            fao2,
+            fmo,
+            fmo2,
+            fpo,
+            fpo2,
+            aao,
+            aao2,
+            amo,
+            amo2,
+            apo,
+            apo2,
+            pam,
+            ppm,
+            aam,
+            aam2,
+            amm,
+            amm2,
+            apm,
+            apm2,
+            pan,
+            ppn,
+            fan,
+            fan2,
+            fmn,
+            fmn2,
+            fpn,
+            fpn2,
+            aan,
+            aan2,
+            amn,
+            amn2,
+            apn,
+            apn2,
+            rai,
+            rai2,
+            lai,
+            lai2,
+            rmi,
+            rmi2,
+            lmi,
+            lmi2,
+            pap,
+            ppp,
+            fap,
+            fap2,
+            fmp,
+            fmp2,
+            fpp,
+            fpp2,
+            aap,
+            aap2,
+            amp,
+            amp2,
+            app,
+            app2,
+            teos,
+            teos2,
+            tos,
+            tos2,
+            iterative,
+            iterative_mid,
+            deponency: self.deponency,
+        };
+        if self.deponency == Deponency::Middle {
+            if VerbStemSet::is_active_aorist(&self.aorist_stem) {
+                verb.drop_aorist_middle(false);
+            }
+            if VerbStemSet::is_active_aorist(&self.aorist_stem_alt) {
+                verb.drop_aorist_middle(true);
+            }
+        }
+        if !self.augmented() {
+            self.drop_augment(&self.aorist_stem, &mut verb.aai);
+            self.drop_augment(&self.aorist_stem, &mut verb.ami);
+            self.drop_augment(&self.aorist_stem_alt, &mut verb.aai2);
+            self.drop_augment(&self.aorist_stem_alt, &mut verb.ami2);
+            self.drop_augment(&self.aor_pass_stem, &mut verb.api);
+            self.drop_augment(&self.aor_pass_stem_alt, &mut verb.api2);
+        }
+        match self.dialect {
+            Dialect::Homeric { .. } => verb.ionic(),
+            Dialect::Koine => verb.koine(),
+            Dialect::Attic => {}
         }
+        self.accent(&mut verb);
+        verb.movable_nu(nu);
+        verb
     }
 }
 
@@ -1504,4 +4175,620 @@ pub struct Verb {
     pub ami2: Conjugated,
     pub api: Conjugated,
     pub api2: Conjugated,
+    pub pas: Conjugated,
+    pub pps: Conjugated,
+    pub aas: Conjugated,
+    pub aas2: Conjugated,
+    pub ams: Conjugated,
+    pub ams2: Conjugated,
+    pub aps: Conjugated,
+    pub aps2: Conjugated,
+    pub pao: Conjugated,
+    pub ppo: Conjugated,
+    pub fao: Conjugated,
+    pub fao2: Conjugated,
+    pub fmo: Conjugated,
+    pub fmo2: Conjugated,
+    pub fpo: Conjugated,
+    pub fpo2: Conjugated,
+    pub aao: Conjugated,
+    pub aao2: Conjugated,
+    pub amo: Conjugated,
+    pub amo2: Conjugated,
+    pub apo: Conjugated,
+    pub apo2: Conjugated,
+    pub pam: Conjugated,
+    pub ppm: Conjugated,
+    pub aam: Conjugated,
+    pub aam2: Conjugated,
+    pub amm: Conjugated,
+    pub amm2: Conjugated,
+    pub apm: Conjugated,
+    pub apm2: Conjugated,
+    pub pan: Conjugated,
+    pub ppn: Conjugated,
+    pub fan: Conjugated,
+    pub fan2: Conjugated,
+    pub fmn: Conjugated,
+    pub fmn2: Conjugated,
+    pub fpn: Conjugated,
+    pub fpn2: Conjugated,
+    pub aan: Conjugated,
+    pub aan2: Conjugated,
+    pub amn: Conjugated,
+    pub amn2: Conjugated,
+    pub apn: Conjugated,
+    pub apn2: Conjugated,
+    pub rai: Conjugated,
+    pub rai2: Conjugated,
+    pub lai: Conjugated,
+    pub lai2: Conjugated,
+    pub rmi: Conjugated,
+    pub rmi2: Conjugated,
+    pub lmi: Conjugated,
+    pub lmi2: Conjugated,
+    pub pap: Declined,
+    pub ppp: Declined,
+    pub fap: Declined,
+    pub fap2: Declined,
+    pub fmp: Declined,
+    pub fmp2: Declined,
+    pub fpp: Declined,
+    pub fpp2: Declined,
+    pub aap: Declined,
+    pub aap2: Declined,
+    pub amp: Declined,
+    pub amp2: Declined,
+    pub app: Declined,
+    pub app2: Declined,
+    // verbal adjectives in -τεος and -τος
+    pub teos: Declined,
+    pub teos2: Declined,
+    pub tos: Declined,
+    pub tos2: Declined,
+    // Homer's iteratives in -σκον, -σκομην
+    pub iterative: Conjugated,
+    pub iterative_mid: Conjugated,
+    pub deponency: Deponency,
+}
+
+// Rows are labelled for deponents when their forms are middle or passive;
+// a passive deponent's aorist and future active cells are passive in form too.
+impl Verb {
+    fn labels(&self) -> (Deponency, Deponency) {
+        match self.deponency {
+            Deponency::Passive => (Deponency::Passive, Deponency::Passive),
+            dep => (dep, Deponency::None),
+        }
+    }
+
+    pub fn conjugations(&self) -> Vec<(&Conjugated, Deponency)> {
+        let (mid, pass) = self.labels();
+        let none = Deponency::None;
+        vec![
+            (&self.pai, none),
+            (&self.ppi, mid),
+            (&self.iai, none),
+            (&self.ipi, mid),
+            (&self.iterative, none),
+            (&self.iterative_mid, mid),
+            (&self.fai, pass),
+            (&self.fai2, pass),
+            (&self.fmi, mid),
+            (&self.fmi2, mid),
+            (&self.fpi, none),
+            (&self.fpi2, none),
+            (&self.aai, pass),
+            (&self.aai2, pass),
+            (&self.ami, mid),
+            (&self.ami2, mid),
+            (&self.api, none),
+            (&self.api2, none),
+            (&self.pas, none),
+            (&self.pps, mid),
+            (&self.aas, pass),
+            (&self.aas2, pass),
+            (&self.ams, mid),
+            (&self.ams2, mid),
+            (&self.aps, none),
+            (&self.aps2, none),
+            (&self.pao, none),
+            (&self.ppo, mid),
+            (&self.fao, pass),
+            (&self.fao2, pass),
+            (&self.fmo, mid),
+            (&self.fmo2, mid),
+            (&self.fpo, none),
+            (&self.fpo2, none),
+            (&self.aao, pass),
+            (&self.aao2, pass),
+            (&self.amo, mid),
+            (&self.amo2, mid),
+            (&self.apo, none),
+            (&self.apo2, none),
+            (&self.pam, none),
+            (&self.ppm, mid),
+            (&self.aam, pass),
+            (&self.aam2, pass),
+            (&self.amm, mid),
+            (&self.amm2, mid),
+            (&self.apm, none),
+            (&self.apm2, none),
+            (&self.pan, none),
+            (&self.ppn, mid),
+            (&self.fan, pass),
+            (&self.fan2, pass),
+            (&self.fmn, mid),
+            (&self.fmn2, mid),
+            (&self.fpn, none),
+            (&self.fpn2, none),
+            (&self.aan, pass),
+            (&self.aan2, pass),
+            (&self.amn, mid),
+            (&self.amn2, mid),
+            (&self.apn, none),
+            (&self.apn2, none),
+            (&self.rai, none),
+            (&self.rai2, none),
+            (&self.lai, none),
+            (&self.lai2, none),
+            (&self.rmi, mid),
+            (&self.rmi2, mid),
+            (&self.lmi, mid),
+            (&self.lmi2, mid),
+        ]
+    }
+
+    pub fn participles(&self) -> Vec<(&Declined, Deponency)> {
+        let (mid, pass) = self.labels();
+        let none = Deponency::None;
+        vec![
+            (&self.pap, none),
+            (&self.ppp, mid),
+            (&self.fap, pass),
+            (&self.fap2, pass),
+            (&self.fmp, mid),
+            (&self.fmp2, mid),
+            (&self.fpp, none),
+            (&self.fpp2, none),
+            (&self.aap, pass),
+            (&self.aap2, pass),
+            (&self.amp, mid),
+            (&self.amp2, mid),
+            (&self.app, none),
+            (&self.app2, none),
+        ]
+    }
+
+    pub fn verbal_adjectives(&self) -> Vec<&Declined> {
+        vec![&self.teos, &self.teos2, &self.tos, &self.tos2]
+    }
+
+    // Ionic leaves the 2nd singular middle open where Attic contracts it,
+    // λυεαι, λυηαι, ἐλυεο, ἐλυσαο, and runs the dative plural out to -οισι
+    // and -ῃσι
+    fn ionic(&mut self) {
+        for conj in [
+            &mut self.ppi,
+            &mut self.fmi,
+            &mut self.fmi2,
+            &mut self.fpi,
+            &mut self.fpi2,
+        ] {
+            conj.replace_ending(1, "ῃ", "εαι");
+        }
+        for conj in [&mut self.pps, &mut self.ams, &mut self.ams2] {
+            conj.replace_ending(1, "ῃ", "ηαι");
+        }
+        for conj in [&mut self.ipi, &mut self.ami, &mut self.ami2] {
+            conj.replace_ending(1, "ου", "εο");
+            conj.replace_ending(1, "ω", "αο");
+        }
+        for conj in [&mut self.ppm, &mut self.amm, &mut self.amm2] {
+            conj.replace_ending(1, "ου", "εο");
+        }
+        for part in [
+            &mut self.pap,
+            &mut self.ppp,
+            &mut self.fap,
+            &mut self.fap2,
+            &mut self.fmp,
+            &mut self.fmp2,
+            &mut self.fpp,
+            &mut self.fpp2,
+            &mut self.aap,
+            &mut self.aap2,
+            &mut self.amp,
+            &mut self.amp2,
+            &mut self.app,
+            &mut self.app2,
+            &mut self.teos,
+            &mut self.teos2,
+            &mut self.tos,
+            &mut self.tos2,
+        ] {
+            part.replace_endings("οις", "οισι");
+            part.replace_endings("αις", "ῃσι");
+        }
+    }
+
+    // The thematic imperfect may take -οσαν in the 3rd plural (εἰχοσαν), and
+    // the optative has all but gone, so it is left out
+    fn koine(&mut self) {
+        self.iai.replace_ending(5, "ον", "οσαν");
+        for conj in [
+            &mut self.pao,
+            &mut self.ppo,
+            &mut self.fao,
+            &mut self.fao2,
+            &mut self.fmo,
+            &mut self.fmo2,
+            &mut self.fpo,
+            &mut self.fpo2,
+            &mut self.aao,
+            &mut self.aao2,
+            &mut self.amo,
+            &mut self.amo2,
+            &mut self.apo,
+            &mut self.apo2,
+        ] {
+            *conj = Conjugated::None;
+        }
+    }
+
+    // A middle deponent whose aorist is active has no aorist middle beside
+    // it: ἐρχομαι has ἠλθον, and ἠλθομην is no form of it
+    fn drop_aorist_middle(&mut self, alt: bool) {
+        let (conjs, part) = if alt {
+            (
+                [
+                    &mut self.ami2,
+                    &mut self.ams2,
+                    &mut self.amo2,
+                    &mut self.amm2,
+                    &mut self.amn2,
+                ],
+                &mut self.amp2,
+            )
+        } else {
+            (
+                [
+                    &mut self.ami,
+                    &mut self.ams,
+                    &mut self.amo,
+                    &mut self.amm,
+                    &mut self.amn,
+                ],
+                &mut self.amp,
+            )
+        };
+        for conj in conjs {
+            *conj = Conjugated::None;
+        }
+        *part = Declined::None;
+    }
+
+    // The recessive accent, for forms not already accented; participles and
+    // verbal adjectives are accented by the stem set, which knows their kind
+    fn accent(&mut self, floor: usize) {
+        for conj in [
+            &mut self.pai,
+            &mut self.ppi,
+            &mut self.iai,
+            &mut self.ipi,
+            &mut self.iterative,
+            &mut self.iterative_mid,
+            &mut self.fai,
+            &mut self.fai2,
+            &mut self.fmi,
+            &mut self.fmi2,
+            &mut self.fpi,
+            &mut self.fpi2,
+            &mut self.aai,
+            &mut self.aai2,
+            &mut self.ami,
+            &mut self.ami2,
+            &mut self.api,
+            &mut self.api2,
+            &mut self.pas,
+            &mut self.pps,
+            &mut self.aas,
+            &mut self.aas2,
+            &mut self.ams,
+            &mut self.ams2,
+            &mut self.aps,
+            &mut self.aps2,
+            &mut self.pam,
+            &mut self.ppm,
+            &mut self.aam,
+            &mut self.aam2,
+            &mut self.amm,
+            &mut self.amm2,
+            &mut self.apm,
+            &mut self.apm2,
+            &mut self.pan,
+            &mut self.ppn,
+            &mut self.fan,
+            &mut self.fan2,
+            &mut self.fmn,
+            &mut self.fmn2,
+            &mut self.fpn,
+            &mut self.fpn2,
+            &mut self.aan,
+            &mut self.aan2,
+            &mut self.amn,
+            &mut self.amn2,
+            &mut self.apn,
+            &mut self.apn2,
+            &mut self.rai,
+            &mut self.rai2,
+            &mut self.rmi,
+            &mut self.rmi2,
+            &mut self.lai,
+            &mut self.lai2,
+            &mut self.lmi,
+            &mut self.lmi2,
+        ] {
+            conj.accent(false, floor);
+        }
+        // -οι and -αι are long in the optative
+        for conj in [
+            &mut self.pao,
+            &mut self.ppo,
+            &mut self.fao,
+            &mut self.fao2,
+            &mut self.fmo,
+            &mut self.fmo2,
+            &mut self.fpo,
+            &mut self.fpo2,
+            &mut self.aao,
+            &mut self.aao2,
+            &mut self.amo,
+            &mut self.amo2,
+            &mut self.apo,
+            &mut self.apo2,
+        ] {
+            conj.accent(true, floor);
+        }
+    }
+
+    fn movable_nu(&mut self, nu: MovableNu) {
+        for conj in [&mut self.lai, &mut self.lai2] {
+            conj.movable_nu(nu, true);
+        }
+        for conj in [
+            &mut self.pai,
+            &mut self.ppi,
+            &mut self.iai,
+            &mut self.ipi,
+            &mut self.iterative,
+            &mut self.iterative_mid,
+            &mut self.fai,
+            &mut self.fai2,
+            &mut self.fmi,
+            &mut self.fmi2,
+            &mut self.fpi,
+            &mut self.fpi2,
+            &mut self.aai,
+            &mut self.aai2,
+            &mut self.ami,
+            &mut self.ami2,
+            &mut self.api,
+            &mut self.api2,
+            &mut self.pas,
+            &mut self.pps,
+            &mut self.aas,
+            &mut self.aas2,
+            &mut self.ams,
+            &mut self.ams2,
+            &mut self.aps,
+            &mut self.aps2,
+            &mut self.pao,
+            &mut self.ppo,
+            &mut self.fao,
+            &mut self.fao2,
+            &mut self.fmo,
+            &mut self.fmo2,
+            &mut self.fpo,
+            &mut self.fpo2,
+            &mut self.aao,
+            &mut self.aao2,
+            &mut self.amo,
+            &mut self.amo2,
+            &mut self.apo,
+            &mut self.apo2,
+            &mut self.pam,
+            &mut self.ppm,
+            &mut self.aam,
+            &mut self.aam2,
+            &mut self.amm,
+            &mut self.amm2,
+            &mut self.apm,
+            &mut self.apm2,
+            &mut self.pan,
+            &mut self.ppn,
+            &mut self.fan,
+            &mut self.fan2,
+            &mut self.fmn,
+            &mut self.fmn2,
+            &mut self.fpn,
+            &mut self.fpn2,
+            &mut self.aan,
+            &mut self.aan2,
+            &mut self.amn,
+            &mut self.amn2,
+            &mut self.apn,
+            &mut self.apn2,
+            &mut self.rai,
+            &mut self.rai2,
+            &mut self.rmi,
+            &mut self.rmi2,
+            &mut self.lmi,
+            &mut self.lmi2,
+        ] {
+            conj.movable_nu(nu, false);
+        }
+        for part in [
+            &mut self.pap,
+            &mut self.ppp,
+            &mut self.fap,
+            &mut self.fap2,
+            &mut self.fmp,
+            &mut self.fmp2,
+            &mut self.fpp,
+            &mut self.fpp2,
+            &mut self.aap,
+            &mut self.aap2,
+            &mut self.amp,
+            &mut self.amp2,
+            &mut self.app,
+            &mut self.app2,
+        ] {
+            part.movable_nu(nu);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use csv::Reader;
+
+    // A verb conjugated from one row of principal parts
+    fn conjugated_in(parts: &str, dialect: Dialect) -> Verb {
+        let csv = format!(
+            "Present,Future,Aorist,Aorist_Passive,Perfect,Perfect_Middle\n{}\n",
+            parts
+        );
+        let entry: VerbEntry = Reader::from_reader(csv.as_bytes())
+            .deserialize()
+            .next()
+            .unwrap()
+            .unwrap();
+        entry
+            .check_alternates()
+            .parse(&Lexicon::builtin(), dialect)
+            .conjugate(MovableNu::Never)
+    }
+
+    fn conjugated(parts: &str) -> Verb {
+        conjugated_in(parts, Dialect::Attic)
+    }
+
+    fn first(conj: &Conjugated) -> &str {
+        match conj.to_vec(false) {
+            Some(forms) => &forms[0],
+            None => panic!("no forms"),
+        }
+    }
+
+    #[test]
+    fn rho_augment() {
+        let verb = conjugated("ῥιπτω,ῥιψω,ἐρριψα,ἐρριφθην,,");
+        assert_eq!(first(&verb.aai), "ἔρριψα");
+        assert_eq!(first(&verb.iai), "ἔρριπτον");
+        assert_eq!(first(&verb.aas), "ῥίψω");
+        assert_eq!(first(&verb.fpi), "ῥιφθήσομαι");
+    }
+
+    // καθιζω and καθευδω are augmented outside the preverb
+    #[test]
+    fn lexicalised() {
+        let verb = conjugated("καθιζω,καθιω,ἐκαθισα,,,");
+        assert_eq!(first(&verb.iai), "ἐκάθιζον");
+        assert_eq!(first(&verb.aai), "ἐκάθισα");
+        assert_eq!(first(&verb.aas), "καθίσω");
+        let verb = conjugated("καθευδω,καθευδησω,,,,");
+        assert_eq!(first(&verb.iai), "ἐκάθευδον");
+    }
+
+    // συνεκπεμπω is augmented inside both preverbs, and the augment comes
+    // off again for the future passive
+    #[test]
+    fn double_preverbs() {
+        let verb = conjugated("συνεκπεμπω,συνεκπεμψω,συνεξεπεμψα,συνεξεπεμφθην,,");
+        assert_eq!(first(&verb.iai), "συνεξέπεμπον");
+        assert_eq!(first(&verb.aai), "συνεξέπεμψα");
+        assert_eq!(first(&verb.fpi), "συνεκπεμφθήσομαι");
+        assert_eq!(first(&verb.aas), "συνεκπέμψω");
+    }
+
+    // a compound's κ and root aorist subjunctives keep the accent on the
+    // contracted vowel rather than drawing it back to the preverb
+    #[test]
+    fn compound_subjunctives() {
+        let verb = conjugated("ἀποδιδωμι,ἀποδωσω,ἀπεδωκα,,,");
+        assert_eq!(first(&verb.aas), "ἀποδῶ");
+        let verb = conjugated("ἀφιστημι,ἀποστησω,ἀπεστην,,,");
+        assert_eq!(first(&verb.aas), "ἀποστῶ");
+    }
+
+    #[test]
+    fn mi_third_plurals() {
+        let verb = conjugated("ἱστημι,στησω,ἐστην,,,");
+        assert_eq!(verb.pai.to_vec(false).unwrap()[5], "ἱστᾶσι");
+        let verb = conjugated("διδωμι,δωσω,ἐδωκα,,,");
+        assert_eq!(verb.pai.to_vec(false).unwrap()[5], "διδόασι");
+    }
+
+    // the 3rd plural of a consonant stem is two words, each accented
+    #[test]
+    fn periphrastic_third_plurals() {
+        let verb = conjugated("γραφω,γραψω,ἐγραψα,ἐγραφην,γεγραφα,γεγραμμαι");
+        assert_eq!(verb.rmi.to_vec(false).unwrap()[5], "γεγραμμένοι εἰσί");
+        assert_eq!(verb.lmi.to_vec(false).unwrap()[5], "γεγραμμένοι ἦσαν");
+    }
+
+    // a long ῡ or ῑ before the σ takes the circumflex: λῦσαι, ῥῖψαι
+    #[test]
+    fn long_vowels() {
+        let verb = conjugated("λυω,λυσω,ἐλυσα,ἐλυθην,λελυκα,λελυμαι");
+        assert_eq!(first(&verb.aan), "λῦσαι");
+        assert_eq!(verb.aam.to_vec(false).unwrap()[1], "λῦσον");
+        assert_eq!(first(&verb.aai), "ἔλυσα");
+        let verb = conjugated("ῥιπτω,ῥιψω,ἐρριψα,ἐρριφθην,,");
+        assert_eq!(first(&verb.aan), "ῥῖψαι");
+        let verb = conjugated("παιδευω,παιδευσω,ἐπαιδευσα,,,");
+        assert_eq!(first(&verb.aan), "παιδεῦσαι");
+    }
+
+    #[test]
+    fn strong_aorist_middle_imperatives() {
+        let verb = conjugated("ἀγω,ἀξω,ἠγαγον,ἠχθην,,");
+        assert_eq!(verb.amm.to_vec(false).unwrap()[1], "ἀγαγοῦ");
+        assert_eq!(first(&verb.amn), "ἀγαγέσθαι");
+        let verb = conjugated("γιγνομαι,γενησομαι,ἐγενομην,,,");
+        assert_eq!(verb.amm.to_vec(false).unwrap()[1], "γενοῦ");
+    }
+
+    // ἐρχομαι's aorist is active, so it has no aorist middle rows
+    #[test]
+    fn active_aorist_deponents() {
+        for dialect in [Dialect::Attic, Dialect::Koine] {
+            let verb = conjugated_in("ἐρχομαι,ἐλευσομαι,ἠλθον,,,", dialect);
+            assert!(verb.aai.to_vec(false).is_some());
+            for conj in [&verb.ami, &verb.ams, &verb.amo, &verb.amm, &verb.amn] {
+                assert!(conj.to_vec(false).is_none());
+            }
+            assert!(verb.amp.to_vecs().is_none());
+        }
+        let verb = conjugated("δεχομαι,δεξομαι,ἐδεξαμην,,,");
+        assert_eq!(first(&verb.ami), "ἐδεξάμην");
+    }
+
+    // εἶμι and εἰμί are told apart by their accents alone
+    #[test]
+    fn eimi() {
+        let verb = conjugated("εἶμι,,,,,");
+        assert_eq!(first(&verb.pai), "εἶμι");
+        assert_eq!(first(&verb.iai), "ᾖα");
+        assert_eq!(first(&verb.pas), "ἴω");
+        assert_eq!(verb.pam.to_vec(false).unwrap()[1], "ἴθι");
+        assert_eq!(first(&verb.pan), "ἰέναι");
+        let verb = conjugated("εἰμί,ἐσομαι,,,,");
+        assert_eq!(first(&verb.pai), "εἰμί");
+        assert_eq!(first(&verb.iai), "ἦ");
+        let verb = conjugated("ἐρχομαι,εἶμι,ἠλθον,,,");
+        assert_eq!(first(&verb.fai), "εἶμι");
+    }
 }